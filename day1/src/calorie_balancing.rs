@@ -0,0 +1,209 @@
+use crate::calorie_counting::Troup;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub calories: u64,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BalancePlan {
+    pub moves: Vec<Move>,
+    pub loads: Vec<(usize, u64)>,
+    pub lower_bound: u64,
+}
+
+impl BalancePlan {
+    pub fn max_load(&self) -> u64 {
+        self.loads.iter().map(|&(_, load)| load).max().unwrap_or(0)
+    }
+}
+
+enum Transfer {
+    Give(usize),
+    Swap(usize, usize),
+}
+
+struct Carrier {
+    id: usize,
+    items: Vec<u64>,
+    load: u128,
+}
+
+fn distance_from_half(amount: u64, gap: u128) -> u128 {
+    (amount as u128 * 2).abs_diff(gap)
+}
+
+fn find_transfer(heavy: &Carrier, light: &Carrier) -> Option<Transfer> {
+    let gap = heavy.load - light.load;
+    let mut best: Option<(u128, Transfer)> = None;
+
+    for (give_index, &given) in heavy.items.iter().enumerate() {
+        if given > 0 && (given as u128) < gap {
+            let distance = distance_from_half(given, gap);
+            if best.as_ref().is_none_or(|(d, _)| distance < *d) {
+                best = Some((distance, Transfer::Give(give_index)));
+            }
+        }
+
+        for (take_index, &taken) in light.items.iter().enumerate() {
+            if given > taken && ((given - taken) as u128) < gap {
+                let distance = distance_from_half(given - taken, gap);
+                if best.as_ref().is_none_or(|(d, _)| distance < *d) {
+                    best = Some((distance, Transfer::Swap(give_index, take_index)));
+                }
+            }
+        }
+    }
+
+    best.map(|(_, transfer)| transfer)
+}
+
+fn move_item(carriers: &mut [Carrier], from: usize, to: usize, index: usize) -> Move {
+    let calories = carriers[from].items.swap_remove(index);
+    carriers[from].load -= calories as u128;
+    carriers[to].load += calories as u128;
+    carriers[to].items.push(calories);
+
+    Move {
+        calories,
+        from: carriers[from].id,
+        to: carriers[to].id,
+    }
+}
+
+fn apply_transfer(
+    carriers: &mut [Carrier],
+    heavy: usize,
+    light: usize,
+    transfer: Transfer,
+) -> Vec<Move> {
+    match transfer {
+        Transfer::Give(index) => vec![move_item(carriers, heavy, light, index)],
+        Transfer::Swap(give_index, take_index) => {
+            let given = move_item(carriers, heavy, light, give_index);
+            let taken = move_item(carriers, light, heavy, take_index);
+            vec![given, taken]
+        }
+    }
+}
+
+fn rebalance(carriers: &mut [Carrier]) -> Vec<Move> {
+    let mut moves = Vec::new();
+
+    'balancing: loop {
+        let heavy = (0..carriers.len())
+            .max_by_key(|&index| (carriers[index].load, std::cmp::Reverse(index)))
+            .unwrap_or(0);
+
+        let mut lighter: Vec<usize> = (0..carriers.len())
+            .filter(|&index| carriers[index].load < carriers[heavy].load)
+            .collect();
+        lighter.sort_by_key(|&index| (carriers[index].load, index));
+
+        for light in lighter {
+            if let Some(transfer) = find_transfer(&carriers[heavy], &carriers[light]) {
+                moves.extend(apply_transfer(carriers, heavy, light, transfer));
+                continue 'balancing;
+            }
+        }
+
+        return moves;
+    }
+}
+
+fn longest_processing_time(carriers: &[Carrier]) -> (Vec<Carrier>, Vec<Move>) {
+    let mut items: Vec<(u64, usize)> = carriers
+        .iter()
+        .enumerate()
+        .flat_map(|(owner, carrier)| carrier.items.iter().map(move |&calories| (calories, owner)))
+        .collect();
+    items.sort_by(|a, b| b.cmp(a));
+
+    let mut packed: Vec<Carrier> = carriers
+        .iter()
+        .map(|carrier| Carrier {
+            id: carrier.id,
+            items: Vec::new(),
+            load: 0,
+        })
+        .collect();
+
+    let mut moves = Vec::new();
+    for (calories, owner) in items {
+        let lightest = packed.iter().map(|carrier| carrier.load).min().unwrap_or(0);
+        let target = if packed[owner].load == lightest {
+            owner
+        } else {
+            packed
+                .iter()
+                .position(|carrier| carrier.load == lightest)
+                .unwrap_or(owner)
+        };
+
+        packed[target].items.push(calories);
+        packed[target].load += calories as u128;
+
+        if target != owner {
+            moves.push(Move {
+                calories,
+                from: carriers[owner].id,
+                to: carriers[target].id,
+            });
+        }
+    }
+
+    (packed, moves)
+}
+
+fn max_load(carriers: &[Carrier]) -> u128 {
+    carriers
+        .iter()
+        .map(|carrier| carrier.load)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn plan_balance(troup: &Troup) -> BalancePlan {
+    let mut carriers: Vec<Carrier> = troup
+        .iter_sorted()
+        .map(|elf| Carrier {
+            id: elf.id(),
+            items: elf.calories().to_vec(),
+            load: elf.total_calories() as u128,
+        })
+        .collect();
+    carriers.sort_by_key(|carrier| carrier.id);
+
+    if carriers.is_empty() {
+        return BalancePlan::default();
+    }
+
+    let total: u128 = carriers.iter().map(|carrier| carrier.load).sum();
+    let largest_item = carriers
+        .iter()
+        .flat_map(|carrier| carrier.items.iter().copied())
+        .max()
+        .unwrap_or(0);
+    let lower_bound = (total.div_ceil(carriers.len() as u128) as u64).max(largest_item);
+
+    let (mut packed, mut packed_moves) = longest_processing_time(&carriers);
+    packed_moves.extend(rebalance(&mut packed));
+
+    let mut moves = rebalance(&mut carriers);
+
+    if (max_load(&packed), packed_moves.len()) < (max_load(&carriers), moves.len()) {
+        carriers = packed;
+        moves = packed_moves;
+    }
+
+    BalancePlan {
+        moves,
+        loads: carriers
+            .iter()
+            .map(|carrier| (carrier.id, carrier.load as u64))
+            .collect(),
+        lower_bound,
+    }
+}
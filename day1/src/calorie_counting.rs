@@ -1,17 +1,88 @@
-use std::collections::BinaryHeap;
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+    num::IntErrorKind,
+    ops::RangeInclusive,
+};
+
+use crate::ranking::RankTree;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Elf {
-    calories: Vec<i32>,
+    id: usize,
+    source_lines: Option<RangeInclusive<usize>>,
+    calories: Vec<u64>,
+    total_calories: u64,
 }
 
 impl Elf {
-    pub fn add_calories(&mut self, calories: i32) {
+    pub fn with_id(id: usize) -> Self {
+        Elf {
+            id,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_source_lines(mut self, lines: RangeInclusive<usize>) -> Self {
+        self.source_lines = Some(lines);
+        self
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn source_lines(&self) -> Option<&RangeInclusive<usize>> {
+        self.source_lines.as_ref()
+    }
+
+    pub fn add_calories(&mut self, calories: u64) -> Result<(), CalorieOverflowError> {
+        self.total_calories = self
+            .total_calories
+            .checked_add(calories)
+            .ok_or(CalorieOverflowError)?;
         self.calories.push(calories);
+
+        Ok(())
+    }
+
+    fn add_calories_from_line(
+        &mut self,
+        calories: u64,
+        line: usize,
+    ) -> Result<(), CalorieOverflowError> {
+        self.add_calories(calories)?;
+        self.source_lines = match self.source_lines.take() {
+            Some(lines) => Some(*lines.start()..=line),
+            None => Some(line..=line),
+        };
+
+        Ok(())
     }
 
-    pub fn total_calories(&self) -> i32 {
-        self.calories.iter().sum()
+    pub fn calories(&self) -> &[u64] {
+        &self.calories
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.calories.len()
+    }
+
+    pub fn total_calories(&self) -> u64 {
+        self.total_calories
+    }
+}
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "elf #{}", self.id)?;
+        if let Some(lines) = &self.source_lines {
+            write!(f, " (lines {}-{})", lines.start(), lines.end())?;
+        }
+        write!(f, " carries {} calories", self.total_calories())
     }
 }
 
@@ -27,48 +98,267 @@ impl Ord for Elf {
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct CalorieOverflowError;
+
+impl Display for CalorieOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Calorie total does not fit in a u64.")
+    }
+}
+
+impl Error for CalorieOverflowError {}
+
+type RankKey = (Reverse<u64>, usize);
+
 #[derive(Debug, Default)]
 pub struct Troup {
-    elves: BinaryHeap<Elf>,
+    elves: HashMap<usize, Elf>,
+    slots_by_id: HashMap<usize, usize>,
+    ranking: RankTree<RankKey>,
+    next_slot: usize,
 }
 
-impl Troup {
-    pub fn new(lines: &[&str]) -> Self {
-        let mut elves = BinaryHeap::new();
-
-        let mut next_elf = Elf::default();
-        for line in lines {
-            if line.is_empty() {
-                elves.push(next_elf);
-                next_elf = Elf::default();
-            } else {
-                let calories_value = line.parse::<i32>().unwrap();
-                next_elf.add_calories(calories_value);
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateTroupError {
+    UnknownElf { id: usize },
+    DuplicateElf { id: usize },
+    CalorieOverflow { id: usize },
+}
+
+impl Display for UpdateTroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateTroupError::UnknownElf { id } => write!(f, "No elf #{id} in troup."),
+            UpdateTroupError::DuplicateElf { id } => write!(f, "Elf #{id} is already in troup."),
+            UpdateTroupError::CalorieOverflow { id } => {
+                write!(f, "Calorie total of elf #{id} would overflow.")
             }
         }
+    }
+}
+
+impl Error for UpdateTroupError {}
+
+#[derive(Debug)]
+pub enum ParseTroupError {
+    Io(io::Error),
+    InvalidCalories { line: usize, text: String },
+    NegativeCalories { line: usize, text: String },
+    CalorieOverflow { line: usize },
+}
 
-        Troup { elves }
+impl Display for ParseTroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseTroupError::Io(error) => write!(f, "Unable to read inventory: {error}"),
+            ParseTroupError::InvalidCalories { line, text } => {
+                write!(f, "Invalid calorie value on line {line}: {text:?}")
+            }
+            ParseTroupError::NegativeCalories { line, text } => {
+                write!(f, "Negative calorie value on line {line}: {text:?}")
+            }
+            ParseTroupError::CalorieOverflow { line } => {
+                write!(f, "Elf calorie total overflows on line {line}")
+            }
+        }
     }
+}
 
-    pub fn get_heaviest_elf(&mut self) -> Option<Elf> {
-        self.elves.pop()
+impl Error for ParseTroupError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseTroupError::Io(error) => Some(error),
+            _ => None,
+        }
     }
+}
 
-    pub fn add_elf(&mut self, elf: Elf) {
-        self.elves.push(elf);
+impl From<io::Error> for ParseTroupError {
+    fn from(value: io::Error) -> Self {
+        ParseTroupError::Io(value)
     }
 }
 
-pub fn get_top_3_heaviest_elves(troup: &mut Troup) -> Vec<Elf> {
-    let mut elves = Vec::new();
+fn is_negative_number(line: &str) -> bool {
+    line.strip_prefix('-')
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn parse_calories(number: usize, line: &str) -> Result<u64, ParseTroupError> {
+    line.parse::<u64>().map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow => ParseTroupError::CalorieOverflow { line: number },
+        _ if is_negative_number(line) => ParseTroupError::NegativeCalories {
+            line: number,
+            text: line.to_string(),
+        },
+        _ => ParseTroupError::InvalidCalories {
+            line: number,
+            text: line.to_string(),
+        },
+    })
+}
+
+#[derive(Default)]
+struct TroupParser {
+    troup: Troup,
+    next_elf: Option<Elf>,
+}
 
-    for _ in 0..3 {
-        elves.push(troup.get_heaviest_elf().unwrap());
+impl TroupParser {
+    fn parse_line(&mut self, number: usize, line: &str) -> Result<(), ParseTroupError> {
+        let line = line.trim();
+
+        if line.is_empty() {
+            if let Some(elf) = self.next_elf.take() {
+                self.troup.insert_elf(elf);
+            }
+        } else {
+            let calories_value = parse_calories(number, line)?;
+            let next_id = self.troup.len() + 1;
+            self.next_elf
+                .get_or_insert_with(|| Elf::with_id(next_id))
+                .add_calories_from_line(calories_value, number)
+                .map_err(|_| ParseTroupError::CalorieOverflow { line: number })?;
+        }
+
+        Ok(())
     }
 
-    elves
+    fn finish(mut self) -> Troup {
+        if let Some(elf) = self.next_elf {
+            self.troup.insert_elf(elf);
+        }
+
+        self.troup
+    }
+}
+
+impl Troup {
+    pub fn new(lines: &[&str]) -> Result<Self, ParseTroupError> {
+        let mut parser = TroupParser::default();
+
+        for (index, line) in lines.iter().enumerate() {
+            parser.parse_line(index + 1, line)?;
+        }
+
+        Ok(parser.finish())
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseTroupError> {
+        let mut parser = TroupParser::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            parser.parse_line(index + 1, &line?)?;
+        }
+
+        Ok(parser.finish())
+    }
+
+    fn slot_of(&self, id: usize) -> Option<usize> {
+        self.slots_by_id.get(&id).copied()
+    }
+
+    fn rank_key(&self, slot: usize) -> Option<RankKey> {
+        self.elves
+            .get(&slot)
+            .map(|elf| (Reverse(elf.total_calories()), slot))
+    }
+
+    fn remove_slot(&mut self, slot: usize) -> Option<Elf> {
+        let key = self.rank_key(slot)?;
+        self.ranking.remove(&key);
+
+        let elf = self.elves.remove(&slot)?;
+        self.slots_by_id.remove(&elf.id);
+
+        Some(elf)
+    }
+
+    pub fn get_elf(&self, id: usize) -> Option<&Elf> {
+        self.slot_of(id).and_then(|slot| self.elves.get(&slot))
+    }
+
+    pub fn get_heaviest_elf(&mut self) -> Option<Elf> {
+        let &(_, slot) = self.ranking.select(0)?;
+        self.remove_slot(slot)
+    }
+
+    pub fn add_elf(&mut self, elf: Elf) -> Result<(), UpdateTroupError> {
+        if self.slots_by_id.contains_key(&elf.id) {
+            return Err(UpdateTroupError::DuplicateElf { id: elf.id });
+        }
+
+        self.insert_elf(elf);
+        Ok(())
+    }
+
+    // Callers must have checked that the id is not taken yet.
+    fn insert_elf(&mut self, elf: Elf) {
+        let slot = self.next_slot;
+        self.next_slot += 1;
+
+        self.ranking.insert((Reverse(elf.total_calories()), slot));
+        self.slots_by_id.insert(elf.id, slot);
+        self.elves.insert(slot, elf);
+    }
+
+    pub fn remove_elf(&mut self, id: usize) -> Option<Elf> {
+        let slot = self.slot_of(id)?;
+        self.remove_slot(slot)
+    }
+
+    pub fn add_calories_to(&mut self, id: usize, calories: u64) -> Result<(), UpdateTroupError> {
+        let slot = self
+            .slot_of(id)
+            .ok_or(UpdateTroupError::UnknownElf { id })?;
+        let old_key = self
+            .rank_key(slot)
+            .ok_or(UpdateTroupError::UnknownElf { id })?;
+
+        let elf = self
+            .elves
+            .get_mut(&slot)
+            .ok_or(UpdateTroupError::UnknownElf { id })?;
+        elf.add_calories(calories)
+            .map_err(|_| UpdateTroupError::CalorieOverflow { id })?;
+        let new_key = (Reverse(elf.total_calories()), slot);
+
+        self.ranking.remove(&old_key);
+        self.ranking.insert(new_key);
+
+        Ok(())
+    }
+
+    pub fn rank(&self, id: usize) -> Option<usize> {
+        let key = self.rank_key(self.slot_of(id)?)?;
+        self.ranking.rank(&key).map(|index| index + 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    pub fn iter_sorted(&self) -> impl Iterator<Item = &Elf> {
+        self.ranking.iter().map(|(_, slot)| &self.elves[slot])
+    }
+
+    pub fn top_n(&self, n: usize) -> Vec<&Elf> {
+        self.iter_sorted().take(n).collect()
+    }
 }
 
-pub fn get_total_elves_weight(elves: &[Elf]) -> i32 {
-    elves.iter().fold(0, |acc, elf| acc + elf.total_calories())
+pub fn get_top_3_heaviest_elves(troup: &Troup) -> Vec<Elf> {
+    troup.top_n(3).into_iter().cloned().collect()
+}
+
+pub fn get_total_elves_weight(elves: &[Elf]) -> u128 {
+    elves
+        .iter()
+        .map(|elf| u128::from(elf.total_calories()))
+        .sum()
 }
@@ -0,0 +1,159 @@
+use std::{error::Error, fmt::Display};
+
+use crate::calorie_counting::Troup;
+
+pub const USAGE: &str = "Usage: day1 [--top N] [--format plain|json] [PATH | -]";
+
+pub const DEFAULT_TOP: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    Path(String),
+    Stdin,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    pub input: Input,
+    pub top: Option<usize>,
+    pub format: OutputFormat,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            input: Input::Path("./data/day1.txt".to_string()),
+            top: None,
+            format: OutputFormat::Plain,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliError {
+    HelpRequested,
+    MissingValue(String),
+    InvalidTop(String),
+    UnknownFormat(String),
+    UnknownOption(String),
+    UnexpectedArgument(String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::HelpRequested => write!(f, "{USAGE}"),
+            CliError::MissingValue(option) => write!(f, "Missing value for {option}."),
+            CliError::InvalidTop(value) => write!(f, "Invalid --top value: {value:?}."),
+            CliError::UnknownFormat(value) => write!(f, "Unknown output format: {value:?}."),
+            CliError::UnknownOption(option) => write!(f, "Unknown option: {option}."),
+            CliError::UnexpectedArgument(argument) => {
+                write!(f, "Unexpected argument: {argument:?}.")
+            }
+        }
+    }
+}
+
+impl Error for CliError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReportError {
+    EmptyTroup,
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportError::EmptyTroup => write!(f, "No heaviest elf if no elves."),
+        }
+    }
+}
+
+impl Error for ReportError {}
+
+impl Options {
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut options = Options::default();
+        let mut input = None;
+
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(CliError::HelpRequested),
+                "-n" | "--top" => {
+                    let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                    options.top = Some(value.parse().map_err(|_| CliError::InvalidTop(value))?);
+                }
+                "-f" | "--format" => {
+                    let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                    options.format = match value.as_str() {
+                        "plain" => OutputFormat::Plain,
+                        "json" => OutputFormat::Json,
+                        _ => return Err(CliError::UnknownFormat(value)),
+                    };
+                }
+                "-" if input.is_none() => input = Some(Input::Stdin),
+                option if option.starts_with('-') && option != "-" => {
+                    return Err(CliError::UnknownOption(arg));
+                }
+                _ if input.is_none() => input = Some(Input::Path(arg)),
+                _ => return Err(CliError::UnexpectedArgument(arg)),
+            }
+        }
+
+        if let Some(input) = input {
+            options.input = input;
+        }
+
+        Ok(options)
+    }
+
+    pub fn top_size(&self) -> usize {
+        self.top.unwrap_or(DEFAULT_TOP)
+    }
+}
+
+pub fn report(troup: &Troup, options: &Options) -> Result<String, ReportError> {
+    let heaviest = troup
+        .top_n(1)
+        .into_iter()
+        .next()
+        .ok_or(ReportError::EmptyTroup)?
+        .total_calories();
+
+    let top = troup.top_n(options.top_size());
+    let top_total: u128 = top.iter().map(|elf| u128::from(elf.total_calories())).sum();
+
+    Ok(match options.format {
+        OutputFormat::Plain => match options.top {
+            None => format!("Part 1: {heaviest}\nPart 2: {top_total}\n"),
+            Some(n) => format!("Part 1: {heaviest}\nTop {n}: {top_total}\n"),
+        },
+        OutputFormat::Json => {
+            let elves: Vec<String> = top
+                .iter()
+                .map(|elf| {
+                    format!(
+                        "{{\"id\":{},\"calories\":{}}}",
+                        elf.id(),
+                        elf.total_calories()
+                    )
+                })
+                .collect();
+            format!(
+                "{{\"heaviest\":{heaviest},\"top\":[{}],\"top_total\":{top_total}}}\n",
+                elves.join(",")
+            )
+        }
+    })
+}
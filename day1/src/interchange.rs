@@ -0,0 +1,193 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    io::{Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::calorie_counting::{Elf, Troup};
+
+#[derive(Debug)]
+pub enum InterchangeError {
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    CalorieOverflow { id: usize },
+    DuplicateElf { id: usize },
+}
+
+impl Display for InterchangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InterchangeError::Json(error) => write!(f, "Invalid troup JSON: {error}"),
+            InterchangeError::Csv(error) => write!(f, "Invalid troup CSV: {error}"),
+            InterchangeError::CalorieOverflow { id } => {
+                write!(f, "Calorie total of elf #{id} overflows.")
+            }
+            InterchangeError::DuplicateElf { id } => write!(f, "Elf #{id} appears twice."),
+        }
+    }
+}
+
+impl Error for InterchangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InterchangeError::Json(error) => Some(error),
+            InterchangeError::Csv(error) => Some(error),
+            InterchangeError::CalorieOverflow { .. } | InterchangeError::DuplicateElf { .. } => {
+                None
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for InterchangeError {
+    fn from(value: serde_json::Error) -> Self {
+        InterchangeError::Json(value)
+    }
+}
+
+impl From<csv::Error> for InterchangeError {
+    fn from(value: csv::Error) -> Self {
+        InterchangeError::Csv(value)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ElfRecord {
+    id: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_lines: Option<(usize, usize)>,
+    calories: Vec<u64>,
+}
+
+impl From<&Elf> for ElfRecord {
+    fn from(value: &Elf) -> Self {
+        ElfRecord {
+            id: value.id(),
+            source_lines: value
+                .source_lines()
+                .map(|lines| (*lines.start(), *lines.end())),
+            calories: value.calories().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<ElfRecord> for Elf {
+    type Error = InterchangeError;
+
+    fn try_from(value: ElfRecord) -> Result<Self, Self::Error> {
+        let mut elf = Elf::with_id(value.id);
+
+        if let Some((first, last)) = value.source_lines {
+            elf = elf.with_source_lines(first..=last);
+        }
+
+        for calories in value.calories {
+            elf.add_calories(calories)
+                .map_err(|_| InterchangeError::CalorieOverflow { id: value.id })?;
+        }
+
+        Ok(elf)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TroupRecord {
+    elves: Vec<ElfRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CalorieRow {
+    elf_id: usize,
+    first_line: Option<usize>,
+    last_line: Option<usize>,
+    calories: Option<u64>,
+}
+
+fn records(troup: &Troup) -> Vec<ElfRecord> {
+    let mut elves: Vec<&Elf> = troup.iter_sorted().collect();
+    elves.sort_by_key(|elf| elf.id());
+
+    elves.into_iter().map(ElfRecord::from).collect()
+}
+
+fn from_records(records: Vec<ElfRecord>) -> Result<Troup, InterchangeError> {
+    let mut troup = Troup::default();
+
+    for record in records {
+        let id = record.id;
+        troup
+            .add_elf(Elf::try_from(record)?)
+            .map_err(|_| InterchangeError::DuplicateElf { id })?;
+    }
+
+    Ok(troup)
+}
+
+impl Troup {
+    pub fn write_json(&self, writer: impl Write) -> Result<(), InterchangeError> {
+        let record = TroupRecord {
+            elves: records(self),
+        };
+        serde_json::to_writer_pretty(writer, &record)?;
+
+        Ok(())
+    }
+
+    pub fn read_json(reader: impl Read) -> Result<Self, InterchangeError> {
+        let record: TroupRecord = serde_json::from_reader(reader)?;
+        from_records(record.elves)
+    }
+
+    pub fn write_csv(&self, writer: impl Write) -> Result<(), InterchangeError> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        for record in records(self) {
+            let (first_line, last_line) = record.source_lines.unzip();
+            let row = |calories| CalorieRow {
+                elf_id: record.id,
+                first_line,
+                last_line,
+                calories,
+            };
+
+            if record.calories.is_empty() {
+                writer.serialize(row(None))?;
+            }
+            for &calories in &record.calories {
+                writer.serialize(row(Some(calories)))?;
+            }
+        }
+
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+
+    pub fn read_csv(reader: impl Read) -> Result<Self, InterchangeError> {
+        let mut reader = csv::Reader::from_reader(reader);
+
+        let mut records: Vec<ElfRecord> = Vec::new();
+        let mut indices: HashMap<usize, usize> = HashMap::new();
+
+        for row in reader.deserialize() {
+            let row: CalorieRow = row?;
+
+            let index = *indices.entry(row.elf_id).or_insert_with(|| {
+                records.push(ElfRecord {
+                    id: row.elf_id,
+                    source_lines: row.first_line.zip(row.last_line),
+                    calories: Vec::new(),
+                });
+                records.len() - 1
+            });
+
+            if let Some(calories) = row.calories {
+                records[index].calories.push(calories);
+            }
+        }
+
+        from_records(records)
+    }
+}
@@ -1 +1,7 @@
+pub mod calorie_balancing;
 pub mod calorie_counting;
+pub mod cli;
+#[cfg(feature = "interchange")]
+pub mod interchange;
+pub mod ranking;
+pub mod statistics;
@@ -1,23 +1,55 @@
-use std::{error::Error, fs};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    process::ExitCode,
+};
 
-use day1::calorie_counting::{get_top_3_heaviest_elves, get_total_elves_weight, Troup};
+use day1::{
+    calorie_counting::Troup,
+    cli::{report, CliError, Input, Options, USAGE},
+};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = fs::read_to_string("./data/day1.txt")?;
-    let lines: Vec<&str> = file.lines().collect();
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(CliError::HelpRequested) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
 
-    let mut troup = Troup::new(&lines);
+    let troup = match &options.input {
+        Input::Stdin => Troup::from_reader(io::stdin().lock()),
+        Input::Path(path) => match File::open(path) {
+            Ok(file) => Troup::from_reader(BufReader::new(file)),
+            Err(error) => {
+                eprintln!("Unable to open {path}: {error}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
 
-    let heaviest_elf = troup
-        .get_heaviest_elf()
-        .ok_or("No heaviest elf if no elves.")?;
-    let total_calories = heaviest_elf.total_calories();
-    println!("Part 1: {total_calories}");
+    let troup = match troup {
+        Ok(troup) => troup,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
 
-    troup.add_elf(heaviest_elf);
-    let top_3 = get_top_3_heaviest_elves(&mut troup);
-    let total_weight = get_total_elves_weight(&top_3);
-    println!("Part 2: {total_weight}");
-
-    Ok(())
+    match report(&troup, &options) {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
 }
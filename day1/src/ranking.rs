@@ -0,0 +1,297 @@
+type Tree<K> = Option<Box<Node<K>>>;
+
+#[derive(Debug)]
+struct Node<K> {
+    key: K,
+    priority: u64,
+    size: usize,
+    left: Tree<K>,
+    right: Tree<K>,
+}
+
+impl<K> Node<K> {
+    fn new(key: K, priority: u64) -> Self {
+        Node {
+            key,
+            priority,
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update_size(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+fn size<K>(tree: &Tree<K>) -> usize {
+    tree.as_ref().map_or(0, |node| node.size)
+}
+
+fn split<K: Ord>(tree: Tree<K>, key: &K) -> (Tree<K>, Tree<K>) {
+    match tree {
+        None => (None, None),
+        Some(mut node) => {
+            if node.key < *key {
+                let (left, right) = split(node.right.take(), key);
+                node.right = left;
+                node.update_size();
+                (Some(node), right)
+            } else {
+                let (left, right) = split(node.left.take(), key);
+                node.left = right;
+                node.update_size();
+                (left, Some(node))
+            }
+        }
+    }
+}
+
+fn merge<K>(left: Tree<K>, right: Tree<K>) -> Tree<K> {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update_size();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update_size();
+                Some(right)
+            }
+        }
+    }
+}
+
+fn remove_first<K>(tree: Tree<K>) -> (Option<K>, Tree<K>) {
+    match tree {
+        None => (None, None),
+        Some(mut node) => match node.left.take() {
+            None => (Some(node.key), node.right.take()),
+            Some(left) => {
+                let (first, left) = remove_first(Some(left));
+                node.left = left;
+                node.update_size();
+                (first, Some(node))
+            }
+        },
+    }
+}
+
+fn first_key_is<K: Ord>(mut node: &Node<K>, key: &K) -> bool {
+    while let Some(left) = &node.left {
+        node = left;
+    }
+
+    node.key == *key
+}
+
+#[derive(Debug)]
+pub struct RankTree<K> {
+    root: Tree<K>,
+    seed: u64,
+}
+
+impl<K> Default for RankTree<K> {
+    fn default() -> Self {
+        RankTree {
+            root: None,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl<K: Ord> RankTree<K> {
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.contains(&key) {
+            return false;
+        }
+
+        let priority = self.next_priority();
+        let (left, right) = split(self.root.take(), &key);
+        let node = Some(Box::new(Node::new(key, priority)));
+        self.root = merge(merge(left, node), right);
+
+        true
+    }
+
+    pub fn remove(&mut self, key: &K) -> bool {
+        let (left, right) = split(self.root.take(), key);
+
+        let (right, removed) = match right {
+            Some(node) if first_key_is(&node, key) => (remove_first(Some(node)).1, true),
+            right => (right, false),
+        };
+
+        self.root = merge(left, right);
+        removed
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.rank(key).is_some()
+    }
+
+    pub fn rank(&self, key: &K) -> Option<usize> {
+        let mut node = self.root.as_deref();
+        let mut smaller = 0;
+
+        while let Some(current) = node {
+            match key.cmp(&current.key) {
+                std::cmp::Ordering::Less => node = current.left.as_deref(),
+                std::cmp::Ordering::Equal => return Some(smaller + size(&current.left)),
+                std::cmp::Ordering::Greater => {
+                    smaller += size(&current.left) + 1;
+                    node = current.right.as_deref();
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn select(&self, mut index: usize) -> Option<&K> {
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            let left_size = size(&current.left);
+            match index.cmp(&left_size) {
+                std::cmp::Ordering::Less => node = current.left.as_deref(),
+                std::cmp::Ordering::Equal => return Some(&current.key),
+                std::cmp::Ordering::Greater => {
+                    index -= left_size + 1;
+                    node = current.right.as_deref();
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn iter(&self) -> Iter<'_, K> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+
+pub struct Iter<'a, K> {
+    stack: Vec<&'a Node<K>>,
+}
+
+impl<'a, K> Iter<'a, K> {
+    fn push_left(&mut self, mut node: Option<&'a Node<K>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K> Iterator for Iter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(&node.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _empty_tree_should_have_no_ranks() {
+        let tree: RankTree<i32> = RankTree::default();
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.rank(&1), None);
+        assert_eq!(tree.select(0), None);
+    }
+
+    #[test]
+    fn _should_rank_keys_in_sorted_order() {
+        let mut tree = RankTree::default();
+        for key in [50, 10, 40, 20, 30] {
+            assert!(tree.insert(key));
+        }
+
+        assert_eq!(tree.len(), 5);
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<i32>>(),
+            [10, 20, 30, 40, 50]
+        );
+        assert_eq!(tree.rank(&10), Some(0));
+        assert_eq!(tree.rank(&40), Some(3));
+        assert_eq!(tree.rank(&35), None);
+        assert_eq!(tree.select(2), Some(&30));
+        assert_eq!(tree.select(5), None);
+    }
+
+    #[test]
+    fn _should_not_insert_duplicate_keys() {
+        let mut tree = RankTree::default();
+
+        assert!(tree.insert(1));
+        assert!(!tree.insert(1));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn _should_remove_only_present_keys() {
+        let mut tree = RankTree::default();
+        for key in 0..100 {
+            tree.insert(key);
+        }
+
+        assert!(tree.remove(&42));
+        assert!(!tree.remove(&42));
+        assert!(!tree.remove(&100));
+        assert_eq!(tree.len(), 99);
+        assert_eq!(tree.rank(&43), Some(42));
+        assert_eq!(tree.select(42), Some(&43));
+    }
+
+    #[test]
+    fn _should_match_sorted_vec_after_mixed_updates() {
+        let mut tree = RankTree::default();
+        let mut expected = Vec::new();
+
+        for step in 0..500u64 {
+            let key = (step * 7919) % 211;
+            if step % 3 == 0 {
+                assert_eq!(tree.remove(&key), expected.contains(&key));
+                expected.retain(|&existing| existing != key);
+            } else {
+                assert_eq!(tree.insert(key), !expected.contains(&key));
+                if !expected.contains(&key) {
+                    expected.push(key);
+                }
+            }
+        }
+        expected.sort();
+
+        assert_eq!(tree.iter().copied().collect::<Vec<u64>>(), expected);
+        for (index, key) in expected.iter().enumerate() {
+            assert_eq!(tree.rank(key), Some(index));
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::calorie_counting::Troup;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HistogramBin {
+    pub start: u64,
+    pub end: u64,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    total: u128,
+    mean: f64,
+    median: f64,
+    standard_deviation: f64,
+    item_counts: Vec<(usize, usize)>,
+    sorted_totals: Vec<u64>,
+}
+
+impl Statistics {
+    pub fn count(&self) -> usize {
+        self.sorted_totals.len()
+    }
+
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.standard_deviation
+    }
+
+    pub fn item_counts(&self) -> &[(usize, usize)] {
+        &self.item_counts
+    }
+
+    pub fn min(&self) -> u64 {
+        self.sorted_totals[0]
+    }
+
+    pub fn max(&self) -> u64 {
+        self.sorted_totals[self.sorted_totals.len() - 1]
+    }
+
+    pub fn percentile(&self, percent: f64) -> Option<f64> {
+        if !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+
+        let rank = percent / 100.0 * (self.count() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        let lower_value = self.sorted_totals[lower] as f64;
+        let upper_value = self.sorted_totals[upper] as f64;
+
+        Some(lower_value + (upper_value - lower_value) * (rank - lower as f64))
+    }
+
+    // Bins never extend past `max()`, so fewer than `bin_count` bins come back when the
+    // totals span fewer values than were asked for.
+    pub fn histogram(&self, bin_count: usize) -> Vec<HistogramBin> {
+        if bin_count == 0 {
+            return Vec::new();
+        }
+
+        let min = self.min() as u128;
+        let max = self.max() as u128;
+        let span = max - min + 1;
+        let bin_width = span.div_ceil((bin_count as u128).min(span));
+
+        let mut bins: Vec<HistogramBin> = (0..span.div_ceil(bin_width))
+            .map(|index| HistogramBin {
+                start: (min + index * bin_width) as u64,
+                end: (min + (index + 1) * bin_width - 1).min(max) as u64,
+                count: 0,
+            })
+            .collect();
+
+        for &total in &self.sorted_totals {
+            let index = ((total as u128 - min) / bin_width) as usize;
+            bins[index].count += 1;
+        }
+
+        bins
+    }
+}
+
+impl Troup {
+    pub fn statistics(&self) -> Option<Statistics> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut sorted_totals: Vec<u64> =
+            self.iter_sorted().map(|elf| elf.total_calories()).collect();
+        sorted_totals.reverse();
+
+        let count = sorted_totals.len();
+        let total: u128 = sorted_totals
+            .iter()
+            .map(|&calories| u128::from(calories))
+            .sum();
+        let mean = total as f64 / count as f64;
+
+        let variance = sorted_totals
+            .iter()
+            .map(|&calories| (calories as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let mut item_counts: Vec<(usize, usize)> = self
+            .iter_sorted()
+            .map(|elf| (elf.id(), elf.item_count()))
+            .collect();
+        item_counts.sort();
+
+        let mut statistics = Statistics {
+            total,
+            mean,
+            median: 0.0,
+            standard_deviation: variance.sqrt(),
+            item_counts,
+            sorted_totals,
+        };
+        statistics.median = statistics.percentile(50.0).unwrap_or_default();
+
+        Some(statistics)
+    }
+}
@@ -0,0 +1,136 @@
+use day1::{calorie_balancing::*, calorie_counting::*};
+
+fn total_load(plan: &BalancePlan) -> u64 {
+    plan.loads.iter().map(|&(_, load)| load).sum()
+}
+
+#[test]
+fn _empty_troup_should_need_no_moves() {
+    let plan = plan_balance(&Troup::default());
+
+    assert!(plan.moves.is_empty());
+    assert!(plan.loads.is_empty());
+    assert_eq!(plan.max_load(), 0);
+}
+
+#[test]
+fn _single_elf_should_keep_its_load() {
+    let plan = plan_balance(&Troup::new(&["1000", "2000"]).unwrap());
+
+    assert!(plan.moves.is_empty());
+    assert_eq!(plan.loads, [(1, 3000)]);
+}
+
+#[test]
+fn _balanced_troup_should_need_no_moves() {
+    let plan = plan_balance(&Troup::new(&["1000", "", "500", "500"]).unwrap());
+
+    assert!(plan.moves.is_empty());
+    assert_eq!(plan.max_load(), 1000);
+}
+
+#[test]
+fn _should_move_items_from_heaviest_elf() {
+    let plan = plan_balance(&Troup::new(&["3000", "1000", "2000", "", "", "1000"]).unwrap());
+
+    assert_eq!(
+        plan.moves,
+        [Move {
+            calories: 3000,
+            from: 1,
+            to: 2
+        }]
+    );
+    assert_eq!(plan.loads, [(1, 3000), (2, 4000)]);
+    assert_eq!(plan.lower_bound, 3500);
+}
+
+#[test]
+fn _should_swap_items_when_no_single_move_helps() {
+    let plan = plan_balance(&Troup::new(&["5", "5", "", "3", "3"]).unwrap());
+
+    assert_eq!(
+        plan.moves,
+        [
+            Move {
+                calories: 5,
+                from: 1,
+                to: 2
+            },
+            Move {
+                calories: 3,
+                from: 2,
+                to: 1
+            },
+        ]
+    );
+    assert_eq!(plan.loads, [(1, 8), (2, 8)]);
+}
+
+#[test]
+fn _should_reach_lower_bound_on_sample_inventory() {
+    let troup = Troup::new(&[
+        "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+        "10000",
+    ])
+    .unwrap();
+    let plan = plan_balance(&troup);
+
+    assert_eq!(total_load(&plan), 55_000);
+    assert_eq!(plan.lower_bound, 11_000);
+    assert_eq!(plan.max_load(), 11_000);
+}
+
+#[test]
+fn _replaying_moves_should_produce_reported_loads() {
+    let troup = Troup::new(&[
+        "17", "4", "9", "", "1", "", "30", "2", "2", "", "8", "", "5", "6",
+    ])
+    .unwrap();
+    let plan = plan_balance(&troup);
+
+    let mut loads: Vec<(usize, u64)> = troup
+        .iter_sorted()
+        .map(|elf| (elf.id(), elf.total_calories()))
+        .collect();
+    loads.sort();
+
+    for step in &plan.moves {
+        loads.iter_mut().find(|(id, _)| *id == step.from).unwrap().1 -= step.calories;
+        loads.iter_mut().find(|(id, _)| *id == step.to).unwrap().1 += step.calories;
+    }
+
+    assert_eq!(loads, plan.loads);
+    assert!(plan.max_load() >= plan.lower_bound);
+    assert!(plan.max_load() < 38);
+}
+
+#[test]
+fn _duplicate_ids_should_never_reach_the_plan() {
+    let mut troup = Troup::default();
+
+    let mut first = Elf::default();
+    first.add_calories(10).unwrap();
+    first.add_calories(10).unwrap();
+    let mut second = Elf::default();
+    second.add_calories(10).unwrap();
+
+    assert_eq!(troup.add_elf(first), Ok(()));
+    assert_eq!(
+        troup.add_elf(second),
+        Err(UpdateTroupError::DuplicateElf { id: 0 })
+    );
+    troup.add_elf(Elf::with_id(1)).unwrap();
+
+    let plan = plan_balance(&troup);
+
+    assert_eq!(
+        plan.moves,
+        [Move {
+            calories: 10,
+            from: 0,
+            to: 1
+        }]
+    );
+    assert_eq!(plan.loads, [(0, 10), (1, 10)]);
+}
@@ -1,3 +1,6 @@
+mod common;
+
+use common::sample_troup;
 use day1::calorie_counting::*;
 
 mod elf_struct {
@@ -12,7 +15,7 @@ mod elf_struct {
     #[test]
     fn _elf_with_one_calorie_value_should_weigh_the_value() {
         let mut single_value_elf = Elf::default();
-        single_value_elf.add_calories(42);
+        single_value_elf.add_calories(42).unwrap();
         assert_eq!(single_value_elf.total_calories(), 42);
     }
 }
@@ -27,53 +30,117 @@ mod elf_functions {
         fn _troups_with_3_elves_should_return_all() {
             let mut troup = Troup::default();
 
-            let elf1 = Elf::default();
-            let elf2 = Elf::default();
-            let elf3 = Elf::default();
+            let elf1 = Elf::with_id(1);
+            let elf2 = Elf::with_id(2);
+            let elf3 = Elf::with_id(3);
 
-            troup.add_elf(elf1);
-            troup.add_elf(elf2);
-            troup.add_elf(elf3);
+            troup.add_elf(elf1.clone()).unwrap();
+            troup.add_elf(elf2.clone()).unwrap();
+            troup.add_elf(elf3.clone()).unwrap();
 
-            assert_eq!(
-                get_top_3_heaviest_elves(&mut troup),
-                [Elf::default(), Elf::default(), Elf::default()]
-            );
+            assert_eq!(get_top_3_heaviest_elves(&troup), [elf1, elf2, elf3]);
         }
 
         #[test]
         fn _should_return_top_3_heaviest_elves() {
             let mut troup = Troup::default();
 
-            let mut elf1 = Elf::default();
-            elf1.add_calories(1000);
-            elf1.add_calories(2000);
-            elf1.add_calories(3000);
+            let mut elf1 = Elf::with_id(1);
+            elf1.add_calories(1000).unwrap();
+            elf1.add_calories(2000).unwrap();
+            elf1.add_calories(3000).unwrap();
 
-            let mut elf2 = Elf::default();
-            elf2.add_calories(4000);
+            let mut elf2 = Elf::with_id(2);
+            elf2.add_calories(4000).unwrap();
 
-            let mut elf3 = Elf::default();
-            elf3.add_calories(5000);
-            elf3.add_calories(6000);
+            let mut elf3 = Elf::with_id(3);
+            elf3.add_calories(5000).unwrap();
+            elf3.add_calories(6000).unwrap();
 
-            let mut elf4 = Elf::default();
-            elf4.add_calories(7000);
-            elf4.add_calories(8000);
-            elf4.add_calories(9000);
+            let mut elf4 = Elf::with_id(4);
+            elf4.add_calories(7000).unwrap();
+            elf4.add_calories(8000).unwrap();
+            elf4.add_calories(9000).unwrap();
 
-            let mut elf5 = Elf::default();
-            elf5.add_calories(10_000);
+            let mut elf5 = Elf::with_id(5);
+            elf5.add_calories(10_000).unwrap();
 
-            troup.add_elf(elf1);
-            troup.add_elf(elf2);
-            troup.add_elf(elf3.clone());
-            troup.add_elf(elf4.clone());
-            troup.add_elf(elf5.clone());
+            troup.add_elf(elf1).unwrap();
+            troup.add_elf(elf2).unwrap();
+            troup.add_elf(elf3.clone()).unwrap();
+            troup.add_elf(elf4.clone()).unwrap();
+            troup.add_elf(elf5.clone()).unwrap();
 
             let heaviest_3 = vec![elf4, elf3, elf5];
 
-            assert_eq!(get_top_3_heaviest_elves(&mut troup), heaviest_3);
+            assert_eq!(get_top_3_heaviest_elves(&troup), heaviest_3);
+            assert_eq!(troup.len(), 5);
+        }
+
+        #[test]
+        fn _troups_with_fewer_than_3_elves_should_return_all() {
+            let mut troup = Troup::default();
+
+            let mut elf1 = Elf::with_id(1);
+            elf1.add_calories(1000).unwrap();
+
+            let mut elf2 = Elf::with_id(2);
+            elf2.add_calories(2000).unwrap();
+
+            troup.add_elf(elf1.clone()).unwrap();
+            troup.add_elf(elf2.clone()).unwrap();
+
+            assert_eq!(get_top_3_heaviest_elves(&troup), [elf2, elf1]);
+        }
+    }
+
+    mod top_n {
+        use super::*;
+
+        #[test]
+        fn _should_return_heaviest_elves_in_descending_order() {
+            let troup = sample_troup();
+
+            let totals: Vec<u64> = troup
+                .top_n(2)
+                .iter()
+                .map(|elf| elf.total_calories())
+                .collect();
+            assert_eq!(totals, [24_000, 11_000]);
+        }
+
+        #[test]
+        fn _should_return_every_elf_if_n_exceeds_troup_size() {
+            let troup = sample_troup();
+
+            assert_eq!(troup.top_n(10).len(), 5);
+            assert!(troup.top_n(0).is_empty());
+            assert!(Troup::default().top_n(3).is_empty());
+        }
+
+        #[test]
+        fn _should_not_remove_elves_from_troup() {
+            let mut troup = sample_troup();
+
+            troup.top_n(3);
+            troup.top_n(3);
+            assert_eq!(troup.len(), 5);
+
+            assert_eq!(
+                troup.get_heaviest_elf().map(|elf| elf.total_calories()),
+                Some(24_000)
+            );
+        }
+
+        #[test]
+        fn _iter_sorted_should_visit_every_elf_heaviest_first() {
+            let troup = sample_troup();
+
+            let totals: Vec<u64> = troup
+                .iter_sorted()
+                .map(|elf| elf.total_calories())
+                .collect();
+            assert_eq!(totals, [24_000, 11_000, 10_000, 6000, 4000]);
         }
     }
 
@@ -83,16 +150,16 @@ mod elf_functions {
         #[test]
         fn _should_return_total_weight_of_elves() {
             let mut elf3 = Elf::default();
-            elf3.add_calories(5000);
-            elf3.add_calories(6000);
+            elf3.add_calories(5000).unwrap();
+            elf3.add_calories(6000).unwrap();
 
             let mut elf4 = Elf::default();
-            elf4.add_calories(7000);
-            elf4.add_calories(8000);
-            elf4.add_calories(9000);
+            elf4.add_calories(7000).unwrap();
+            elf4.add_calories(8000).unwrap();
+            elf4.add_calories(9000).unwrap();
 
             let mut elf5 = Elf::default();
-            elf5.add_calories(10_000);
+            elf5.add_calories(10_000).unwrap();
 
             let elves = vec![elf4, elf3, elf5];
 
@@ -100,3 +167,328 @@ mod elf_functions {
         }
     }
 }
+
+mod troup_parsing {
+    use super::*;
+
+    #[test]
+    fn _new_should_keep_final_elf_without_trailing_blank_line() {
+        let mut troup = Troup::new(&["1000", "2000", "", "4000"]).unwrap();
+
+        assert_eq!(
+            troup.get_heaviest_elf().map(|elf| elf.total_calories()),
+            Some(4000)
+        );
+        assert_eq!(
+            troup.get_heaviest_elf().map(|elf| elf.total_calories()),
+            Some(3000)
+        );
+        assert_eq!(troup.get_heaviest_elf(), None);
+    }
+
+    #[test]
+    fn _from_reader_should_parse_every_group() -> Result<(), ParseTroupError> {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let mut troup = Troup::from_reader(input.as_bytes())?;
+
+        let totals: Vec<u64> = std::iter::from_fn(|| troup.get_heaviest_elf())
+            .map(|elf| elf.total_calories())
+            .collect();
+        assert_eq!(totals, [24_000, 11_000, 10_000, 6000, 4000]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _from_reader_should_skip_repeated_blank_lines() -> Result<(), ParseTroupError> {
+        let input = "\n\n1000\n\n\n\n2000\n\n";
+        let mut troup = Troup::from_reader(input.as_bytes())?;
+
+        assert_eq!(
+            troup.get_heaviest_elf().map(|elf| elf.total_calories()),
+            Some(2000)
+        );
+        assert_eq!(
+            troup.get_heaviest_elf().map(|elf| elf.total_calories()),
+            Some(1000)
+        );
+        assert_eq!(troup.get_heaviest_elf(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _from_reader_should_report_line_and_text_of_invalid_calories() {
+        let input = "1000\n\n20OO\n3000";
+        let result = Troup::from_reader(input.as_bytes());
+
+        assert!(matches!(
+            result,
+            Err(ParseTroupError::InvalidCalories { line: 3, ref text }) if text == "20OO"
+        ));
+    }
+}
+
+mod elf_provenance {
+    use super::*;
+
+    #[test]
+    fn _parsed_elves_should_be_numbered_in_input_order() -> Result<(), ParseTroupError> {
+        let input = "1000\n2000\n\n\n4000\n\n5000\n6000";
+        let troup = Troup::from_reader(input.as_bytes())?;
+
+        let ids: Vec<usize> = troup.iter_sorted().map(|elf| elf.id()).collect();
+        assert_eq!(ids, [3, 2, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _parsed_elves_should_remember_their_source_lines() -> Result<(), ParseTroupError> {
+        let input = "1000\n2000\n\n\n4000\n\n5000\n6000";
+        let troup = Troup::from_reader(input.as_bytes())?;
+
+        assert_eq!(troup.get_elf(1).and_then(Elf::source_lines), Some(&(1..=2)));
+        assert_eq!(troup.get_elf(2).and_then(Elf::source_lines), Some(&(5..=5)));
+        assert_eq!(troup.get_elf(3).and_then(Elf::source_lines), Some(&(7..=8)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _get_elf_should_return_none_for_unknown_id() {
+        let troup = Troup::new(&["1000", "", "2000"]).unwrap();
+
+        assert!(troup.get_elf(0).is_none());
+        assert!(troup.get_elf(3).is_none());
+    }
+
+    #[test]
+    fn _elf_should_display_id_lines_and_calories() {
+        let troup = Troup::new(&["1000", "", "2000", "3000"]).unwrap();
+
+        let heaviest = troup.get_elf(2).map(|elf| elf.to_string());
+        assert_eq!(
+            heaviest.as_deref(),
+            Some("elf #2 (lines 3-4) carries 5000 calories")
+        );
+
+        let manual = Elf::with_id(7);
+        assert_eq!(manual.to_string(), "elf #7 carries 0 calories");
+    }
+}
+
+mod calorie_arithmetic {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn _add_calories_should_reject_overflowing_total() {
+        let mut elf = Elf::default();
+        elf.add_calories(u64::MAX - 1).unwrap();
+
+        assert_eq!(elf.add_calories(2), Err(CalorieOverflowError));
+        assert_eq!(elf.total_calories(), u64::MAX - 1);
+        assert_eq!(elf.item_count(), 1);
+
+        assert_eq!(elf.add_calories(1), Ok(()));
+        assert_eq!(elf.total_calories(), u64::MAX);
+    }
+
+    #[test]
+    fn _from_reader_should_reject_negative_calories() {
+        let result = Troup::from_reader("1000\n-200\n".as_bytes());
+
+        assert!(matches!(
+            result,
+            Err(ParseTroupError::NegativeCalories { line: 2, ref text }) if text == "-200"
+        ));
+    }
+
+    #[test]
+    fn _new_should_report_invalid_lines_instead_of_panicking() {
+        assert!(matches!(
+            Troup::new(&["-5"]),
+            Err(ParseTroupError::NegativeCalories { line: 1, ref text }) if text == "-5"
+        ));
+        assert!(matches!(
+            Troup::new(&["1000", "", "lots"]),
+            Err(ParseTroupError::InvalidCalories { line: 3, ref text }) if text == "lots"
+        ));
+    }
+
+    #[test]
+    fn _from_reader_should_reject_overflowing_items_and_totals() {
+        let result = Troup::from_reader("18446744073709551616".as_bytes());
+        assert!(matches!(
+            result,
+            Err(ParseTroupError::CalorieOverflow { line: 1 })
+        ));
+
+        let result = Troup::from_reader("1\n\n18446744073709551615\n1".as_bytes());
+        assert!(matches!(
+            result,
+            Err(ParseTroupError::CalorieOverflow { line: 4 })
+        ));
+    }
+
+    #[test]
+    fn _total_weight_should_not_overflow_across_elves() {
+        let mut elf = Elf::default();
+        elf.add_calories(u64::MAX).unwrap();
+
+        let elves = vec![elf.clone(), elf.clone(), elf];
+        assert_eq!(get_total_elves_weight(&elves), 3 * u128::from(u64::MAX));
+    }
+
+    proptest! {
+        #[test]
+        fn _elf_total_should_match_wide_sum_or_report_overflow(
+            items in prop::collection::vec(any::<u64>(), 0..8)
+        ) {
+            let mut elf = Elf::default();
+            let result: Result<(), CalorieOverflowError> =
+                items.iter().try_for_each(|&calories| elf.add_calories(calories));
+
+            let wide_total: u128 = items.iter().map(|&calories| u128::from(calories)).sum();
+            match u64::try_from(wide_total) {
+                Ok(total) => {
+                    prop_assert_eq!(result, Ok(()));
+                    prop_assert_eq!(elf.total_calories(), total);
+                }
+                Err(_) => prop_assert_eq!(result, Err(CalorieOverflowError)),
+            }
+        }
+
+        #[test]
+        fn _parsed_totals_should_match_input_near_u64_boundary(
+            items in prop::collection::vec(u64::MAX / 4..=u64::MAX / 2, 1..3)
+        ) {
+            let input = items
+                .iter()
+                .map(|calories| calories.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+            let mut troup = Troup::from_reader(input.as_bytes()).unwrap();
+
+            let total: u64 = items.iter().sum();
+            prop_assert_eq!(troup.get_heaviest_elf().map(|elf| elf.total_calories()), Some(total));
+        }
+
+        #[test]
+        fn _negative_values_should_never_parse(
+            input in prop_oneof![
+                (i64::MIN..0).prop_map(|calories| calories.to_string()),
+                "-[1-9][0-9]{19,40}",
+            ]
+        ) {
+            let result = Troup::from_reader(input.as_bytes());
+
+            prop_assert!(
+                matches!(result, Err(ParseTroupError::NegativeCalories { line: 1, .. })),
+                "unexpected result {:?}",
+                result
+            );
+        }
+    }
+}
+
+mod live_ranking {
+    use super::*;
+
+    #[test]
+    fn _rank_should_start_at_1_for_heaviest_elf() {
+        let troup = sample_troup();
+
+        assert_eq!(troup.rank(4), Some(1));
+        assert_eq!(troup.rank(3), Some(2));
+        assert_eq!(troup.rank(5), Some(3));
+        assert_eq!(troup.rank(1), Some(4));
+        assert_eq!(troup.rank(2), Some(5));
+        assert_eq!(troup.rank(6), None);
+    }
+
+    #[test]
+    fn _adding_calories_should_update_rank() -> Result<(), UpdateTroupError> {
+        let mut troup = sample_troup();
+
+        troup.add_calories_to(2, 21_000)?;
+        assert_eq!(troup.rank(2), Some(1));
+        assert_eq!(troup.rank(4), Some(2));
+        assert_eq!(troup.get_elf(2).map(Elf::total_calories), Some(25_000));
+        assert_eq!(troup.get_elf(2).map(Elf::item_count), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _adding_calories_should_report_unknown_elves_and_overflow() {
+        let mut troup = sample_troup();
+
+        assert_eq!(
+            troup.add_calories_to(9, 1),
+            Err(UpdateTroupError::UnknownElf { id: 9 })
+        );
+        assert_eq!(
+            troup.add_calories_to(1, u64::MAX),
+            Err(UpdateTroupError::CalorieOverflow { id: 1 })
+        );
+        assert_eq!(troup.get_elf(1).map(Elf::total_calories), Some(6000));
+        assert_eq!(troup.rank(1), Some(4));
+    }
+
+    #[test]
+    fn _removing_elf_should_shift_lower_ranks() {
+        let mut troup = sample_troup();
+
+        let removed = troup.remove_elf(3);
+        assert_eq!(removed.map(|elf| elf.total_calories()), Some(11_000));
+        assert_eq!(troup.len(), 4);
+        assert_eq!(troup.rank(3), None);
+        assert_eq!(troup.rank(5), Some(2));
+        assert_eq!(troup.rank(2), Some(4));
+        assert!(troup.remove_elf(3).is_none());
+    }
+
+    #[test]
+    fn _new_elves_should_join_leaderboard() {
+        let mut troup = sample_troup();
+
+        let mut newcomer = Elf::with_id(6);
+        newcomer.add_calories(12_000).unwrap();
+        troup.add_elf(newcomer).unwrap();
+
+        assert_eq!(troup.rank(6), Some(2));
+        let ids: Vec<usize> = troup.top_n(3).iter().map(|elf| elf.id()).collect();
+        assert_eq!(ids, [4, 6, 3]);
+    }
+
+    #[test]
+    fn _duplicate_ids_should_be_rejected() {
+        let mut troup = sample_troup();
+
+        let mut impostor = Elf::with_id(3);
+        impostor.add_calories(50_000).unwrap();
+
+        assert_eq!(
+            troup.add_elf(impostor),
+            Err(UpdateTroupError::DuplicateElf { id: 3 })
+        );
+        assert_eq!(troup.len(), 5);
+        assert_eq!(troup.get_elf(3).map(Elf::total_calories), Some(11_000));
+        assert_eq!(troup.rank(3), Some(2));
+
+        troup.remove_elf(3);
+        assert_eq!(troup.add_elf(Elf::with_id(3)), Ok(()));
+        assert_eq!(troup.rank(3), Some(5));
+    }
+
+    #[test]
+    fn _popping_heaviest_elf_should_promote_next() {
+        let mut troup = sample_troup();
+
+        assert_eq!(troup.get_heaviest_elf().map(|elf| elf.id()), Some(4));
+        assert_eq!(troup.rank(3), Some(1));
+        assert_eq!(troup.len(), 4);
+    }
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::sample_troup;
+use day1::{calorie_counting::*, cli::*};
+
+mod parse {
+    use super::*;
+
+    #[test]
+    fn _no_arguments_should_use_defaults() {
+        let options = Options::parse(Vec::<String>::new());
+
+        assert_eq!(options, Ok(Options::default()));
+    }
+
+    #[test]
+    fn _should_read_path_top_and_format() {
+        let options = Options::parse(["--top", "5", "input.txt", "--format", "json"]);
+
+        assert_eq!(
+            options,
+            Ok(Options {
+                input: Input::Path("input.txt".to_string()),
+                top: Some(5),
+                format: OutputFormat::Json,
+            })
+        );
+    }
+
+    #[test]
+    fn _dash_should_select_stdin() {
+        let options = Options::parse(["-n", "1", "-"]);
+
+        assert_eq!(
+            options.map(|options| (options.input, options.top)),
+            Ok((Input::Stdin, Some(1)))
+        );
+    }
+
+    #[test]
+    fn _should_reject_bad_arguments() {
+        assert_eq!(
+            Options::parse(["--top"]),
+            Err(CliError::MissingValue("--top".to_string()))
+        );
+        assert_eq!(
+            Options::parse(["--top", "three"]),
+            Err(CliError::InvalidTop("three".to_string()))
+        );
+        assert_eq!(
+            Options::parse(["--format", "xml"]),
+            Err(CliError::UnknownFormat("xml".to_string()))
+        );
+        assert_eq!(
+            Options::parse(["--verbose"]),
+            Err(CliError::UnknownOption("--verbose".to_string()))
+        );
+        assert_eq!(
+            Options::parse(["a.txt", "b.txt"]),
+            Err(CliError::UnexpectedArgument("b.txt".to_string()))
+        );
+        assert_eq!(Options::parse(["-h"]), Err(CliError::HelpRequested));
+    }
+}
+
+mod output {
+    use super::*;
+
+    #[test]
+    fn _plain_report_should_list_both_parts() {
+        let report = report(&sample_troup(), &Options::default());
+
+        assert_eq!(report.as_deref(), Ok("Part 1: 24000\nPart 2: 45000\n"));
+    }
+
+    #[test]
+    fn _plain_report_should_label_explicit_top_sizes() {
+        let troup = sample_troup();
+        let options = |top| Options {
+            top: Some(top),
+            ..Options::default()
+        };
+
+        assert_eq!(
+            report(&troup, &options(3)).as_deref(),
+            Ok("Part 1: 24000\nTop 3: 45000\n")
+        );
+        assert_eq!(
+            report(&troup, &options(5)).as_deref(),
+            Ok("Part 1: 24000\nTop 5: 55000\n")
+        );
+        assert_eq!(
+            report(&troup, &options(0)).as_deref(),
+            Ok("Part 1: 24000\nTop 0: 0\n")
+        );
+    }
+
+    #[test]
+    fn _json_report_should_list_top_elves() {
+        let options = Options {
+            top: Some(2),
+            format: OutputFormat::Json,
+            ..Default::default()
+        };
+        let report = report(&sample_troup(), &options);
+
+        assert_eq!(
+            report.as_deref(),
+            Ok("{\"heaviest\":24000,\"top\":[{\"id\":4,\"calories\":24000},{\"id\":3,\"calories\":11000}],\"top_total\":35000}\n")
+        );
+    }
+
+    #[test]
+    fn _empty_troup_should_fail() {
+        assert_eq!(
+            report(&Troup::default(), &Options::default()),
+            Err(ReportError::EmptyTroup)
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use day1::calorie_counting::Troup;
+
+pub fn sample_troup() -> Troup {
+    Troup::new(&[
+        "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+        "10000",
+    ])
+    .unwrap()
+}
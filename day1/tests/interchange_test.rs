@@ -0,0 +1,126 @@
+#![cfg(feature = "interchange")]
+
+use day1::{calorie_counting::*, interchange::*};
+
+fn sample_troup() -> Troup {
+    Troup::new(&["1000", "2000", "", "4000", "", "", "5000", "6000"]).unwrap()
+}
+
+type ElfSummary = (usize, Option<(usize, usize)>, Vec<u64>);
+
+fn summary(troup: &Troup) -> Vec<ElfSummary> {
+    let mut elves: Vec<ElfSummary> = troup
+        .iter_sorted()
+        .map(|elf| {
+            (
+                elf.id(),
+                elf.source_lines()
+                    .map(|lines| (*lines.start(), *lines.end())),
+                elf.calories().to_vec(),
+            )
+        })
+        .collect();
+    elves.sort();
+    elves
+}
+
+#[test]
+fn _json_should_round_trip_elves_items_and_ids() -> Result<(), InterchangeError> {
+    let troup = sample_troup();
+
+    let mut json = Vec::new();
+    troup.write_json(&mut json)?;
+    let restored = Troup::read_json(json.as_slice())?;
+
+    assert_eq!(summary(&restored), summary(&troup));
+    assert_eq!(restored.rank(3), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn _json_should_accept_elves_without_source_lines() -> Result<(), InterchangeError> {
+    let json = r#"{"elves":[{"id":7,"calories":[1,2,3]},{"id":8,"calories":[]}]}"#;
+    let troup = Troup::read_json(json.as_bytes())?;
+
+    assert_eq!(
+        summary(&troup),
+        [(7, None, vec![1, 2, 3]), (8, None, Vec::new())]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn _json_should_reject_overflowing_elves() {
+    let json = r#"{"elves":[{"id":1,"calories":[18446744073709551615,1]}]}"#;
+
+    assert!(matches!(
+        Troup::read_json(json.as_bytes()),
+        Err(InterchangeError::CalorieOverflow { id: 1 })
+    ));
+}
+
+#[test]
+fn _json_should_reject_duplicate_elves() {
+    let json = r#"{"elves":[{"id":1,"calories":[1000]},{"id":1,"calories":[2000]}]}"#;
+
+    assert!(matches!(
+        Troup::read_json(json.as_bytes()),
+        Err(InterchangeError::DuplicateElf { id: 1 })
+    ));
+}
+
+#[test]
+fn _json_should_reject_malformed_input() {
+    assert!(matches!(
+        Troup::read_json("{\"elves\":[{\"id\":-1}]}".as_bytes()),
+        Err(InterchangeError::Json(_))
+    ));
+}
+
+#[test]
+fn _csv_should_write_one_row_per_item() -> Result<(), InterchangeError> {
+    let mut troup = sample_troup();
+    troup.add_elf(Elf::with_id(4)).unwrap();
+
+    let mut csv = Vec::new();
+    troup.write_csv(&mut csv)?;
+
+    assert_eq!(
+        String::from_utf8_lossy(&csv),
+        "elf_id,first_line,last_line,calories\n\
+         1,1,2,1000\n\
+         1,1,2,2000\n\
+         2,4,4,4000\n\
+         3,7,8,5000\n\
+         3,7,8,6000\n\
+         4,,,\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn _csv_should_round_trip_elves_items_and_ids() -> Result<(), InterchangeError> {
+    let mut troup = sample_troup();
+    troup.add_elf(Elf::with_id(4)).unwrap();
+
+    let mut csv = Vec::new();
+    troup.write_csv(&mut csv)?;
+    let restored = Troup::read_csv(csv.as_slice())?;
+
+    assert_eq!(summary(&restored), summary(&troup));
+
+    Ok(())
+}
+
+#[test]
+fn _csv_should_reject_malformed_rows() {
+    let csv = "elf_id,first_line,last_line,calories\n1,,,lots\n";
+
+    assert!(matches!(
+        Troup::read_csv(csv.as_bytes()),
+        Err(InterchangeError::Csv(_))
+    ));
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::sample_troup;
+use day1::{calorie_counting::*, statistics::*};
+
+#[test]
+fn _empty_troup_should_have_no_statistics() {
+    assert_eq!(Troup::default().statistics(), None);
+}
+
+#[test]
+fn _should_summarise_elf_totals() {
+    let statistics = sample_troup().statistics().unwrap();
+
+    assert_eq!(statistics.count(), 5);
+    assert_eq!(statistics.total(), 55_000);
+    assert_eq!(statistics.mean(), 11_000.0);
+    assert_eq!(statistics.median(), 10_000.0);
+    assert_eq!(statistics.min(), 4000);
+    assert_eq!(statistics.max(), 24_000);
+    assert!((statistics.standard_deviation() - 6985.7000).abs() < 1e-3);
+}
+
+#[test]
+fn _should_interpolate_percentiles() {
+    let statistics = sample_troup().statistics().unwrap();
+
+    assert_eq!(statistics.percentile(0.0), Some(4000.0));
+    assert_eq!(statistics.percentile(25.0), Some(6000.0));
+    assert_eq!(statistics.percentile(87.5), Some(17_500.0));
+    assert_eq!(statistics.percentile(100.0), Some(24_000.0));
+    assert_eq!(statistics.percentile(-1.0), None);
+    assert_eq!(statistics.percentile(100.5), None);
+}
+
+#[test]
+fn _should_bucket_totals_into_equal_width_bins() {
+    let statistics = sample_troup().statistics().unwrap();
+
+    let histogram = statistics.histogram(4);
+    assert_eq!(
+        histogram,
+        [
+            HistogramBin {
+                start: 4000,
+                end: 9000,
+                count: 2
+            },
+            HistogramBin {
+                start: 9001,
+                end: 14_001,
+                count: 2
+            },
+            HistogramBin {
+                start: 14_002,
+                end: 19_002,
+                count: 0
+            },
+            HistogramBin {
+                start: 19_003,
+                end: 24_000,
+                count: 1
+            },
+        ]
+    );
+
+    assert!(statistics.histogram(0).is_empty());
+}
+
+#[test]
+fn _single_elf_should_fill_single_bin() {
+    let statistics = Troup::new(&["500"]).unwrap().statistics().unwrap();
+
+    assert_eq!(statistics.standard_deviation(), 0.0);
+    assert_eq!(
+        statistics
+            .histogram(3)
+            .iter()
+            .map(|bin| bin.count)
+            .sum::<usize>(),
+        1
+    );
+}
+
+#[test]
+fn _should_break_down_item_counts_per_elf() {
+    let statistics = sample_troup().statistics().unwrap();
+
+    assert_eq!(
+        statistics.item_counts(),
+        [(1, 3), (2, 1), (3, 2), (4, 3), (5, 1)]
+    );
+}
+
+#[test]
+fn _histogram_should_not_reach_past_the_largest_total() {
+    let statistics = Troup::new(&["5", "", "7"]).unwrap().statistics().unwrap();
+
+    assert_eq!(
+        statistics.histogram(10),
+        [
+            HistogramBin {
+                start: 5,
+                end: 5,
+                count: 1
+            },
+            HistogramBin {
+                start: 6,
+                end: 6,
+                count: 0
+            },
+            HistogramBin {
+                start: 7,
+                end: 7,
+                count: 1
+            },
+        ]
+    );
+}
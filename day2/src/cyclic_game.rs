@@ -0,0 +1,236 @@
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    error::Error,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::rock_paper_scissors::{Hand, RoundResult};
+
+pub fn cyclic_cmp(hand: usize, other: usize, hand_count: usize) -> Ordering {
+    let distance = (hand + hand_count - other) % hand_count;
+
+    if distance == 0 {
+        Ordering::Equal
+    } else if distance <= hand_count / 2 {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    }
+}
+
+pub fn cyclic_offsets(result: &RoundResult, hand_count: usize) -> Vec<usize> {
+    let half = hand_count / 2;
+
+    match result {
+        RoundResult::Draw => vec![0],
+        RoundResult::Win => (1..=half).collect(),
+        RoundResult::Lose => (1..=half).map(|offset| hand_count - offset).collect(),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameDefinitionError {
+    EvenHandCount(usize),
+    DuplicateHand(String),
+    ScoreCountMismatch { hands: usize, scores: usize },
+}
+
+impl Display for GameDefinitionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameDefinitionError::EvenHandCount(count) => {
+                write!(
+                    f,
+                    "A cyclic game needs an odd number of hands, got {count}."
+                )
+            }
+            GameDefinitionError::DuplicateHand(name) => write!(f, "Duplicate hand: {name}."),
+            GameDefinitionError::ScoreCountMismatch { hands, scores } => {
+                write!(f, "Expected {hands} scores, got {scores}.")
+            }
+        }
+    }
+}
+
+impl Error for GameDefinitionError {}
+
+// A hand remembers the game it came from, so a handle handed to another game is rejected
+// instead of being read as whichever hand sits at the same index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameHand {
+    game: u64,
+    index: usize,
+}
+
+impl GameHand {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+fn game_id(names: &[String], scores: &[i32]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for name in names {
+        name.to_ascii_lowercase().hash(&mut hasher);
+    }
+    scores.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CyclicGame {
+    id: u64,
+    names: Vec<String>,
+    scores: Vec<i32>,
+}
+
+impl CyclicGame {
+    pub fn new(names: &[&str]) -> Result<Self, GameDefinitionError> {
+        let scores: Vec<i32> = (1..=names.len() as i32).collect();
+        Self::with_scores(names, &scores)
+    }
+
+    pub fn with_scores(names: &[&str], scores: &[i32]) -> Result<Self, GameDefinitionError> {
+        if names.len().is_multiple_of(2) {
+            return Err(GameDefinitionError::EvenHandCount(names.len()));
+        }
+
+        if names.len() != scores.len() {
+            return Err(GameDefinitionError::ScoreCountMismatch {
+                hands: names.len(),
+                scores: scores.len(),
+            });
+        }
+
+        for (index, name) in names.iter().enumerate() {
+            if names[..index]
+                .iter()
+                .any(|other| other.eq_ignore_ascii_case(name))
+            {
+                return Err(GameDefinitionError::DuplicateHand(name.to_string()));
+            }
+        }
+
+        Ok(Self::from_parts(
+            names.iter().map(|name| name.to_string()).collect(),
+            scores.to_vec(),
+        ))
+    }
+
+    fn from_parts(names: Vec<String>, scores: Vec<i32>) -> Self {
+        CyclicGame {
+            id: game_id(&names, &scores),
+            names,
+            scores,
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::from_parts(
+            ["Rock", "Paper", "Scissors"].map(String::from).to_vec(),
+            vec![1, 2, 3],
+        )
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::from_parts(
+            ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+                .map(String::from)
+                .to_vec(),
+            vec![1, 2, 3, 4, 5],
+        )
+    }
+
+    fn game_hand(&self, index: usize) -> GameHand {
+        GameHand {
+            game: self.id,
+            index,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn hands(&self) -> impl Iterator<Item = GameHand> {
+        let game = self.id;
+        (0..self.len()).map(move |index| GameHand { game, index })
+    }
+
+    pub fn hand(&self, name: &str) -> Option<GameHand> {
+        self.names
+            .iter()
+            .position(|hand| hand.eq_ignore_ascii_case(name))
+            .map(|index| self.game_hand(index))
+    }
+
+    pub fn from_hand(&self, hand: Hand) -> Option<GameHand> {
+        self.hand(match hand {
+            Hand::Rock => "Rock",
+            Hand::Paper => "Paper",
+            Hand::Scissors => "Scissors",
+        })
+    }
+
+    fn contains(&self, hand: GameHand) -> bool {
+        hand.game == self.id && hand.index < self.len()
+    }
+
+    pub fn name(&self, hand: GameHand) -> Option<&str> {
+        if self.contains(hand) {
+            Some(&self.names[hand.index])
+        } else {
+            None
+        }
+    }
+
+    pub fn hand_score(&self, hand: GameHand) -> Option<i32> {
+        if self.contains(hand) {
+            Some(self.scores[hand.index])
+        } else {
+            None
+        }
+    }
+
+    pub fn compare(&self, hand: GameHand, other: GameHand) -> Option<Ordering> {
+        if self.contains(hand) && self.contains(other) {
+            Some(cyclic_cmp(hand.index, other.index, self.len()))
+        } else {
+            None
+        }
+    }
+
+    pub fn beats(&self, hand: GameHand, other: GameHand) -> bool {
+        self.compare(hand, other) == Some(Ordering::Greater)
+    }
+
+    pub fn result(&self, player: GameHand, opponent: GameHand) -> Option<RoundResult> {
+        self.compare(player, opponent)
+            .map(|ordering| match ordering {
+                Ordering::Less => RoundResult::Lose,
+                Ordering::Equal => RoundResult::Draw,
+                Ordering::Greater => RoundResult::Win,
+            })
+    }
+
+    pub fn score(&self, opponent: GameHand, player: GameHand) -> Option<i32> {
+        Some(self.hand_score(player)? + self.result(player, opponent)? as i32)
+    }
+
+    pub fn hands_for(&self, opponent: GameHand, result: &RoundResult) -> Vec<GameHand> {
+        if !self.contains(opponent) {
+            return Vec::new();
+        }
+
+        cyclic_offsets(result, self.len())
+            .into_iter()
+            .map(|offset| self.game_hand((opponent.index + offset) % self.len()))
+            .collect()
+    }
+}
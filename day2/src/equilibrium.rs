@@ -0,0 +1,137 @@
+use crate::rock_paper_scissors::{Hand, Round};
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equilibrium {
+    pub row_strategy: Vec<f64>,
+    pub column_strategy: Vec<f64>,
+    pub value: f64,
+}
+
+pub fn payoff_matrix(score: impl Fn(Hand, Hand) -> f64) -> Vec<Vec<f64>> {
+    Hand::ALL
+        .iter()
+        .map(|&player| {
+            Hand::ALL
+                .iter()
+                .map(|&opponent| score(opponent, player))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn score_payoffs() -> Vec<Vec<f64>> {
+    payoff_matrix(|opponent, player| Round::new(opponent, player).get_score() as f64)
+}
+
+pub fn score_difference_payoffs() -> Vec<Vec<f64>> {
+    payoff_matrix(|opponent, player| {
+        (Round::new(opponent, player).get_score() - Round::new(player, opponent).get_score()) as f64
+    })
+}
+
+struct Tableau {
+    cells: Vec<Vec<f64>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn pivot(&mut self, row: usize, column: usize) {
+        let divisor = self.cells[row][column];
+        for cell in self.cells[row].iter_mut() {
+            *cell /= divisor;
+        }
+
+        let pivot_row = self.cells[row].clone();
+        for (index, other) in self.cells.iter_mut().enumerate() {
+            let factor = other[column];
+            if index != row && factor.abs() > EPSILON {
+                for (cell, pivot) in other.iter_mut().zip(&pivot_row) {
+                    *cell -= factor * pivot;
+                }
+            }
+        }
+
+        self.basis[row] = column;
+    }
+
+    fn entering_column(&self) -> Option<usize> {
+        let objective = self.cells.last()?;
+        (0..objective.len() - 1).find(|&column| objective[column] < -EPSILON)
+    }
+
+    fn leaving_row(&self, column: usize) -> Option<usize> {
+        let rhs = self.cells[0].len() - 1;
+
+        (0..self.basis.len())
+            .filter(|&row| self.cells[row][column] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio_a = self.cells[a][rhs] / self.cells[a][column];
+                let ratio_b = self.cells[b][rhs] / self.cells[b][column];
+                ratio_a
+                    .total_cmp(&ratio_b)
+                    .then(self.basis[a].cmp(&self.basis[b]))
+            })
+    }
+}
+
+pub fn solve_zero_sum(payoffs: &[Vec<f64>]) -> Option<Equilibrium> {
+    let rows = payoffs.len();
+    let columns = payoffs.first()?.len();
+    if columns == 0 || payoffs.iter().any(|row| row.len() != columns) {
+        return None;
+    }
+
+    let minimum = payoffs
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    let shift = 1.0 - minimum.min(0.0);
+
+    let width = columns + rows + 1;
+    let mut cells = Vec::with_capacity(rows + 1);
+    for (index, row) in payoffs.iter().enumerate() {
+        let mut cells_row = vec![0.0; width];
+        for (column, payoff) in row.iter().enumerate() {
+            cells_row[column] = payoff + shift;
+        }
+        cells_row[columns + index] = 1.0;
+        cells_row[width - 1] = 1.0;
+        cells.push(cells_row);
+    }
+    let mut objective = vec![0.0; width];
+    objective[..columns].fill(-1.0);
+    cells.push(objective);
+
+    let mut tableau = Tableau {
+        cells,
+        basis: (columns..columns + rows).collect(),
+    };
+
+    while let Some(column) = tableau.entering_column() {
+        let row = tableau.leaving_row(column)?;
+        tableau.pivot(row, column);
+    }
+
+    let objective = &tableau.cells[rows];
+    let total = objective[width - 1];
+
+    let mut column_strategy = vec![0.0; columns];
+    for (row, &basic) in tableau.basis.iter().enumerate() {
+        if basic < columns {
+            column_strategy[basic] = tableau.cells[row][width - 1] / total;
+        }
+    }
+
+    let row_strategy = (0..rows)
+        .map(|row| objective[columns + row] / total)
+        .collect();
+
+    Some(Equilibrium {
+        row_strategy,
+        column_strategy,
+        value: 1.0 / total - shift,
+    })
+}
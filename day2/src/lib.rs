@@ -1 +1,7 @@
+pub mod cyclic_game;
+pub mod equilibrium;
+pub mod match_summary;
+pub mod players;
 pub mod rock_paper_scissors;
+pub mod round_decoder;
+pub mod strategy_optimiser;
@@ -0,0 +1,72 @@
+use crate::rock_paper_scissors::{Hand, Round, RoundResult};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HandSummary {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub shape_points: i32,
+    pub outcome_points: i32,
+}
+
+impl HandSummary {
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    pub fn total_points(&self) -> i32 {
+        self.shape_points + self.outcome_points
+    }
+
+    fn add(&mut self, other: &HandSummary) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+        self.shape_points += other.shape_points;
+        self.outcome_points += other.outcome_points;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchSummary {
+    by_hand: [HandSummary; 3],
+}
+
+impl MatchSummary {
+    pub fn from_rounds(rounds: &[Round]) -> Self {
+        let mut summary = MatchSummary::default();
+
+        for round in rounds {
+            summary.record(round);
+        }
+
+        summary
+    }
+
+    pub fn record(&mut self, round: &Round) {
+        let breakdown = round.get_score_breakdown();
+        let hand = &mut self.by_hand[round.player().index()];
+
+        match breakdown.result {
+            RoundResult::Win => hand.wins += 1,
+            RoundResult::Draw => hand.draws += 1,
+            RoundResult::Lose => hand.losses += 1,
+        }
+        hand.shape_points += breakdown.shape;
+        hand.outcome_points += breakdown.outcome;
+    }
+
+    pub fn hand(&self, hand: Hand) -> &HandSummary {
+        &self.by_hand[hand.index()]
+    }
+
+    pub fn total(&self) -> HandSummary {
+        let mut total = HandSummary::default();
+
+        for hand in &self.by_hand {
+            total.add(hand);
+        }
+
+        total
+    }
+}
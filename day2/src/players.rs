@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+
+use crate::rock_paper_scissors::{get_hand_from_opponent_and_result, Hand, Round, RoundResult};
+
+pub trait Player {
+    fn choose(&mut self) -> Hand;
+
+    fn observe(&mut self, _own: Hand, _opponent: Hand) {}
+}
+
+fn counter_to(hand: Hand) -> Hand {
+    get_hand_from_opponent_and_result(&hand, &RoundResult::Win)
+}
+
+fn most_frequent(counts: &[u32; 3]) -> Option<Hand> {
+    let (index, &count) = counts
+        .iter()
+        .enumerate()
+        .max_by_key(|&(index, count)| (count, std::cmp::Reverse(index)))?;
+
+    if count == 0 {
+        None
+    } else {
+        Some(Hand::ALL[index])
+    }
+}
+
+#[derive(Debug)]
+pub struct FixedGuide {
+    hands: Vec<Hand>,
+    position: usize,
+}
+
+impl FixedGuide {
+    #[must_use]
+    pub fn new(hands: Vec<Hand>) -> Option<Self> {
+        if hands.is_empty() {
+            return None;
+        }
+
+        Some(FixedGuide { hands, position: 0 })
+    }
+}
+
+impl Player for FixedGuide {
+    fn choose(&mut self) -> Hand {
+        let hand = self.hands[self.position % self.hands.len()];
+        self.position += 1;
+        hand
+    }
+}
+
+#[derive(Debug)]
+pub struct UniformRandom {
+    state: u64,
+}
+
+impl UniformRandom {
+    pub fn new(seed: u64) -> Self {
+        UniformRandom { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Player for UniformRandom {
+    fn choose(&mut self) -> Hand {
+        Hand::ALL[(self.next_u64() % 3) as usize]
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FrequencyCounter {
+    counts: [u32; 3],
+}
+
+impl Player for FrequencyCounter {
+    fn choose(&mut self) -> Hand {
+        most_frequent(&self.counts).map_or(Hand::Rock, counter_to)
+    }
+
+    fn observe(&mut self, _own: Hand, opponent: Hand) {
+        self.counts[opponent.index()] += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MarkovChain {
+    transitions: [[u32; 3]; 3],
+    last: Option<Hand>,
+}
+
+impl Player for MarkovChain {
+    fn choose(&mut self) -> Hand {
+        self.last
+            .and_then(|last| most_frequent(&self.transitions[last.index()]))
+            .map_or(Hand::Rock, counter_to)
+    }
+
+    fn observe(&mut self, _own: Hand, opponent: Hand) {
+        if let Some(last) = self.last {
+            self.transitions[last.index()][opponent.index()] += 1;
+        }
+        self.last = Some(opponent);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MatchReport {
+    pub rounds: usize,
+    pub first_score: i32,
+    pub second_score: i32,
+    pub first_wins: usize,
+    pub second_wins: usize,
+    pub draws: usize,
+}
+
+pub fn play_match(first: &mut dyn Player, second: &mut dyn Player, rounds: usize) -> MatchReport {
+    let mut report = MatchReport {
+        rounds,
+        ..Default::default()
+    };
+
+    for _ in 0..rounds {
+        let first_hand = first.choose();
+        let second_hand = second.choose();
+
+        report.first_score += Round::new(second_hand, first_hand).get_score();
+        report.second_score += Round::new(first_hand, second_hand).get_score();
+
+        match first_hand.cmp(&second_hand) {
+            Ordering::Greater => report.first_wins += 1,
+            Ordering::Less => report.second_wins += 1,
+            Ordering::Equal => report.draws += 1,
+        }
+
+        first.observe(first_hand, second_hand);
+        second.observe(second_hand, first_hand);
+    }
+
+    report
+}
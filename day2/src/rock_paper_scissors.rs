@@ -1,4 +1,6 @@
-use std::{cmp::Ordering, str::FromStr};
+use std::{cmp::Ordering, error::Error, fmt::Display, str::FromStr};
+
+use crate::cyclic_game::{cyclic_cmp, cyclic_offsets};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Hand {
@@ -7,7 +9,20 @@ pub enum Hand {
     Scissors = 3,
 }
 
-impl Hand {}
+impl Hand {
+    pub const ALL: [Hand; 3] = [Hand::Rock, Hand::Paper, Hand::Scissors];
+
+    pub fn index(&self) -> usize {
+        *self as usize - 1
+    }
+
+    pub fn from_opponent_symbol(symbol: &str) -> Result<Hand, ParseHandError> {
+        match symbol.to_uppercase().as_str() {
+            "A" | "B" | "C" => symbol.parse(),
+            _ => Err(ParseHandError::UnknownSymbol(symbol.to_string())),
+        }
+    }
+}
 
 impl Eq for Hand {}
 
@@ -19,51 +34,30 @@ impl PartialOrd for Hand {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self {
-            Hand::Rock => match other {
-                Hand::Rock => Ordering::Equal,
-                Hand::Paper => Ordering::Less,
-                Hand::Scissors => Ordering::Greater,
-            },
-            Hand::Paper => match other {
-                Hand::Rock => Ordering::Greater,
-                Hand::Paper => Ordering::Equal,
-                Hand::Scissors => Ordering::Less,
-            },
-            Hand::Scissors => match other {
-                Hand::Rock => Ordering::Less,
-                Hand::Paper => Ordering::Greater,
-                Hand::Scissors => Ordering::Equal,
-            },
-        }
+        cyclic_cmp(self.index(), other.index(), Hand::ALL.len())
     }
 }
 
 #[must_use]
 pub fn get_hand_from_opponent_and_result(opponent: &Hand, result: &RoundResult) -> Hand {
-    use Hand::{Paper, Rock, Scissors};
-
-    match result {
-        RoundResult::Draw => match opponent {
-            Rock => Rock,
-            Paper => Paper,
-            Scissors => Scissors,
-        },
-        RoundResult::Lose => match opponent {
-            Rock => Scissors,
-            Paper => Rock,
-            Scissors => Paper,
-        },
-        RoundResult::Win => match opponent {
-            Rock => Paper,
-            Paper => Scissors,
-            Scissors => Rock,
-        },
-    }
-}
-
-#[derive(Debug, PartialEq)]
-pub struct ParseHandError {}
+    let offset = cyclic_offsets(result, Hand::ALL.len())[0];
+    Hand::ALL[(opponent.index() + offset) % Hand::ALL.len()]
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseHandError {
+    UnknownSymbol(String),
+}
+
+impl Display for ParseHandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHandError::UnknownSymbol(symbol) => write!(f, "Unknown hand symbol: {symbol:?}."),
+        }
+    }
+}
+
+impl Error for ParseHandError {}
 
 impl FromStr for Hand {
     type Err = ParseHandError;
@@ -73,19 +67,34 @@ impl FromStr for Hand {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissors),
-            _ => Err(Self::Err {}),
+            _ => Err(ParseHandError::UnknownSymbol(s.to_string())),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RoundResult {
     Lose = 0,
     Draw = 3,
     Win = 6,
 }
 
-#[derive(Debug)]
-pub struct ParseRoundResultError {}
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseRoundResultError {
+    UnknownSymbol(String),
+}
+
+impl Display for ParseRoundResultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRoundResultError::UnknownSymbol(symbol) => {
+                write!(f, "Unknown result symbol: {symbol:?}.")
+            }
+        }
+    }
+}
+
+impl Error for ParseRoundResultError {}
 
 impl FromStr for RoundResult {
     type Err = ParseRoundResultError;
@@ -95,65 +104,178 @@ impl FromStr for RoundResult {
             "X" => Ok(Self::Lose),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Win),
-            _ => Err(Self::Err {}),
+            _ => Err(ParseRoundResultError::UnknownSymbol(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseRoundError {
+    WrongTokenCount(usize),
+    InvalidOpponent(ParseHandError),
+    InvalidPlayer(ParseHandError),
+    InvalidResult(ParseRoundResultError),
+}
+
+impl Display for ParseRoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRoundError::WrongTokenCount(count) => {
+                write!(f, "Expected 2 symbols per round, found {count}.")
+            }
+            ParseRoundError::InvalidOpponent(error) => write!(f, "Invalid opponent: {error}"),
+            ParseRoundError::InvalidPlayer(error) => write!(f, "Invalid player: {error}"),
+            ParseRoundError::InvalidResult(error) => write!(f, "Invalid result: {error}"),
         }
     }
 }
 
-#[derive(Debug)]
+impl Error for ParseRoundError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseRoundError::WrongTokenCount(_) => None,
+            ParseRoundError::InvalidOpponent(error) | ParseRoundError::InvalidPlayer(error) => {
+                Some(error)
+            }
+            ParseRoundError::InvalidResult(error) => Some(error),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRoundsError {
+    pub line: usize,
+    pub error: ParseRoundError,
+}
+
+impl Display for ParseRoundsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.error)
+    }
+}
+
+impl Error for ParseRoundsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+pub fn split_round(s: &str) -> Result<(&str, &str), ParseRoundError> {
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        [first, second] => Ok((first, second)),
+        _ => Err(ParseRoundError::WrongTokenCount(tokens.len())),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    pub shape: i32,
+    pub outcome: i32,
+    pub result: RoundResult,
+}
+
+impl ScoreBreakdown {
+    #[must_use]
+    pub fn total(&self) -> i32 {
+        self.shape + self.outcome
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Round(Hand, Hand);
 
 impl Round {
     #[must_use]
-    pub fn from_hand_hand((hand1, hand2): &(&str, &str)) -> Option<Self> {
-        if let Ok(hand1) = hand1.parse::<Hand>() {
-            if let Ok(hand2) = hand2.parse::<Hand>() {
-                return Some(Round(hand1, hand2));
-            }
+    pub fn new(opponent: Hand, player: Hand) -> Self {
+        Round(opponent, player)
+    }
+
+    pub fn try_from_hand_hand((hand1, hand2): &(&str, &str)) -> Result<Self, ParseRoundError> {
+        let hand1 = hand1.parse().map_err(ParseRoundError::InvalidOpponent)?;
+        let hand2 = hand2.parse().map_err(ParseRoundError::InvalidPlayer)?;
+        Ok(Round(hand1, hand2))
+    }
+
+    pub fn try_from_hand_result((hand, result): &(&str, &str)) -> Result<Self, ParseRoundError> {
+        let opponent = hand.parse().map_err(ParseRoundError::InvalidOpponent)?;
+        let result = result.parse().map_err(ParseRoundError::InvalidResult)?;
+        let player = get_hand_from_opponent_and_result(&opponent, &result);
+        Ok(Round(opponent, player))
+    }
+
+    pub fn parse_with_result(s: &str) -> Result<Self, ParseRoundError> {
+        Self::try_from_hand_result(&split_round(s)?)
+    }
+
+    #[must_use]
+    pub fn from_hand_hand(pair: &(&str, &str)) -> Option<Self> {
+        Self::try_from_hand_hand(pair).ok()
+    }
+
+    #[must_use]
+    pub fn from_hand_result(pair: &(&str, &str)) -> Option<Self> {
+        Self::try_from_hand_result(pair).ok()
+    }
+
+    #[must_use]
+    pub fn opponent(&self) -> Hand {
+        self.0
+    }
+
+    #[must_use]
+    pub fn player(&self) -> Hand {
+        self.1
+    }
+
+    #[must_use]
+    pub fn get_result(&self) -> RoundResult {
+        match self.1.cmp(&self.0) {
+            Ordering::Less => RoundResult::Lose,
+            Ordering::Equal => RoundResult::Draw,
+            Ordering::Greater => RoundResult::Win,
         }
-        None
     }
 
     #[must_use]
-    pub fn from_hand_result((hand, result): &(&str, &str)) -> Option<Self> {
-        if let Ok(opponent) = hand.parse() {
-            if let Ok(result) = result.parse() {
-                let player = get_hand_from_opponent_and_result(&opponent, &result);
-                return Some(Round(opponent, player));
-            }
+    pub fn get_score_breakdown(&self) -> ScoreBreakdown {
+        let result = self.get_result();
+
+        ScoreBreakdown {
+            shape: self.1 as i32,
+            outcome: result as i32,
+            result,
         }
-        None
     }
 
     #[must_use]
     pub fn get_score(&self) -> i32 {
-        self.1 as i32
-            + (match self.1.cmp(&self.0) {
-                Ordering::Less => 0,
-                Ordering::Equal => 3,
-                Ordering::Greater => 6,
-            })
+        self.get_score_breakdown().total()
     }
 }
 
-pub struct ParseRoundError;
-
 impl FromStr for Round {
     type Err = ParseRoundError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let players = s.split(' ').collect::<Vec<&str>>();
-
-        if players.len() != 2 {
-            return Err(Self::Err {});
-        }
-
-        if let (Some(first_player), Some(second_player)) = (players.first(), players.get(1)) {
-            let first_player = first_player.parse().unwrap();
-            let second_player = second_player.parse().unwrap();
-            Ok(Self(first_player, second_player))
-        } else {
-            Err(Self::Err {})
-        }
+        Self::try_from_hand_hand(&split_round(s)?)
     }
 }
+
+pub fn parse_rounds(
+    input: &str,
+    parse: impl Fn(&str) -> Result<Round, ParseRoundError>,
+) -> Result<Vec<Round>, ParseRoundsError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse(line).map_err(|error| ParseRoundsError {
+                line: index + 1,
+                error,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,237 @@
+use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr};
+
+use crate::{
+    rock_paper_scissors::{
+        get_hand_from_opponent_and_result, split_round, Hand, ParseHandError, ParseRoundError,
+        ParseRoundResultError, Round, RoundResult,
+    },
+    strategy_optimiser::ColumnMapping,
+};
+
+pub trait RoundDecoder {
+    fn decode(&self, opponent: &str, column: &str) -> Result<Round, ParseRoundError>;
+
+    fn decode_line(&self, line: &str) -> Result<Round, ParseRoundError> {
+        let (opponent, column) = split_round(line)?;
+        self.decode(opponent, column)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HandDecoder;
+
+impl RoundDecoder for HandDecoder {
+    fn decode(&self, opponent: &str, column: &str) -> Result<Round, ParseRoundError> {
+        Round::try_from_hand_hand(&(opponent, column))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ResultDecoder;
+
+impl RoundDecoder for ResultDecoder {
+    fn decode(&self, opponent: &str, column: &str) -> Result<Round, ParseRoundError> {
+        Round::try_from_hand_result(&(opponent, column))
+    }
+}
+
+impl RoundDecoder for ColumnMapping {
+    fn decode(&self, opponent: &str, column: &str) -> Result<Round, ParseRoundError> {
+        let opponent =
+            Hand::from_opponent_symbol(opponent).map_err(ParseRoundError::InvalidOpponent)?;
+        let index = match column.to_uppercase().as_str() {
+            "X" => 0,
+            "Y" => 1,
+            "Z" => 2,
+            _ => {
+                return Err(match self {
+                    ColumnMapping::Hands(_) => ParseRoundError::InvalidPlayer(
+                        ParseHandError::UnknownSymbol(column.to_string()),
+                    ),
+                    ColumnMapping::Results(_) => ParseRoundError::InvalidResult(
+                        ParseRoundResultError::UnknownSymbol(column.to_string()),
+                    ),
+                })
+            }
+        };
+
+        Ok(self.round(opponent, index))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseColumnTargetError {
+    UnknownTarget(String),
+}
+
+impl Display for ParseColumnTargetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseColumnTargetError::UnknownTarget(target) => {
+                write!(f, "Unknown hand or result: {target:?}.")
+            }
+        }
+    }
+}
+
+impl Error for ParseColumnTargetError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnTarget {
+    Hand(Hand),
+    Result(RoundResult),
+}
+
+impl FromStr for ColumnTarget {
+    type Err = ParseColumnTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rock" => Ok(Self::Hand(Hand::Rock)),
+            "paper" => Ok(Self::Hand(Hand::Paper)),
+            "scissors" => Ok(Self::Hand(Hand::Scissors)),
+            "lose" => Ok(Self::Result(RoundResult::Lose)),
+            "draw" => Ok(Self::Result(RoundResult::Draw)),
+            "win" => Ok(Self::Result(RoundResult::Win)),
+            _ => Err(ParseColumnTargetError::UnknownTarget(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseDecoderError {
+    MissingEquals {
+        line: usize,
+    },
+    MissingColumn {
+        line: usize,
+    },
+    UnknownColumn {
+        line: usize,
+        column: String,
+    },
+    UnknownTarget {
+        line: usize,
+        error: ParseColumnTargetError,
+    },
+    ResultForOpponent {
+        line: usize,
+    },
+}
+
+impl Display for ParseDecoderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDecoderError::MissingEquals { line } => {
+                write!(f, "Line {line}: expected `column.symbol = target`.")
+            }
+            ParseDecoderError::MissingColumn { line } => {
+                write!(f, "Line {line}: expected `column.symbol` before `=`.")
+            }
+            ParseDecoderError::UnknownColumn { line, column } => {
+                write!(f, "Line {line}: unknown column {column:?}.")
+            }
+            ParseDecoderError::UnknownTarget { line, error } => write!(f, "Line {line}: {error}"),
+            ParseDecoderError::ResultForOpponent { line } => {
+                write!(f, "Line {line}: the opponent column can only map to hands.")
+            }
+        }
+    }
+}
+
+impl Error for ParseDecoderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDecoderError::UnknownTarget { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MappingDecoder {
+    opponents: HashMap<String, Hand>,
+    players: HashMap<String, ColumnTarget>,
+}
+
+impl MappingDecoder {
+    pub fn map_opponent(&mut self, symbol: &str, hand: Hand) {
+        self.opponents.insert(symbol.to_uppercase(), hand);
+    }
+
+    pub fn map_player(&mut self, symbol: &str, target: ColumnTarget) {
+        self.players.insert(symbol.to_uppercase(), target);
+    }
+}
+
+impl FromStr for MappingDecoder {
+    type Err = ParseDecoderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut decoder = MappingDecoder::default();
+
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, target) = line
+                .split_once('=')
+                .ok_or(ParseDecoderError::MissingEquals { line: line_number })?;
+            let (column, symbol) = key
+                .trim()
+                .split_once('.')
+                .ok_or(ParseDecoderError::MissingColumn { line: line_number })?;
+
+            let target = target.trim().parse::<ColumnTarget>().map_err(|error| {
+                ParseDecoderError::UnknownTarget {
+                    line: line_number,
+                    error,
+                }
+            })?;
+
+            match (column.trim(), target) {
+                ("opponent", ColumnTarget::Hand(hand)) => decoder.map_opponent(symbol.trim(), hand),
+                ("opponent", ColumnTarget::Result(_)) => {
+                    return Err(ParseDecoderError::ResultForOpponent { line: line_number })
+                }
+                ("player", target) => decoder.map_player(symbol.trim(), target),
+                (column, _) => {
+                    return Err(ParseDecoderError::UnknownColumn {
+                        line: line_number,
+                        column: column.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(decoder)
+    }
+}
+
+impl RoundDecoder for MappingDecoder {
+    fn decode(&self, opponent: &str, column: &str) -> Result<Round, ParseRoundError> {
+        let opponent_hand = *self
+            .opponents
+            .get(&opponent.to_uppercase())
+            .ok_or_else(|| {
+                ParseRoundError::InvalidOpponent(ParseHandError::UnknownSymbol(
+                    opponent.to_string(),
+                ))
+            })?;
+        let target = self.players.get(&column.to_uppercase()).ok_or_else(|| {
+            ParseRoundError::InvalidPlayer(ParseHandError::UnknownSymbol(column.to_string()))
+        })?;
+
+        let player = match target {
+            ColumnTarget::Hand(hand) => *hand,
+            ColumnTarget::Result(result) => {
+                get_hand_from_opponent_and_result(&opponent_hand, result)
+            }
+        };
+
+        Ok(Round::new(opponent_hand, player))
+    }
+}
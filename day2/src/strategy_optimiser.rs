@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use crate::rock_paper_scissors::{get_hand_from_opponent_and_result, Hand, Round, RoundResult};
+
+const COLUMNS: [&str; 3] = ["X", "Y", "Z"];
+
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnMapping {
+    Hands([Hand; 3]),
+    Results([RoundResult; 3]),
+}
+
+impl ColumnMapping {
+    pub fn all() -> Vec<ColumnMapping> {
+        let hands = permutations(Hand::ALL).map(ColumnMapping::Hands);
+        let results = permutations([RoundResult::Lose, RoundResult::Draw, RoundResult::Win])
+            .map(ColumnMapping::Results);
+
+        hands.into_iter().chain(results).collect()
+    }
+
+    pub fn round(&self, opponent: Hand, column: usize) -> Round {
+        match self {
+            ColumnMapping::Hands(hands) => Round::new(opponent, hands[column]),
+            ColumnMapping::Results(results) => Round::new(
+                opponent,
+                get_hand_from_opponent_and_result(&opponent, &results[column]),
+            ),
+        }
+    }
+}
+
+impl Display for ColumnMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let targets: Vec<String> = match self {
+            ColumnMapping::Hands(hands) => hands.iter().map(|hand| format!("{hand:?}")).collect(),
+            ColumnMapping::Results(results) => {
+                results.iter().map(|result| format!("{result:?}")).collect()
+            }
+        };
+
+        let pairs: Vec<String> = COLUMNS
+            .iter()
+            .zip(targets)
+            .map(|(column, target)| format!("{column}={target}"))
+            .collect();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StrategyGuide {
+    counts: [[i32; 3]; 3],
+}
+
+impl StrategyGuide {
+    #[must_use]
+    pub fn from_pairs(pairs: &[(&str, &str)]) -> Option<Self> {
+        let mut guide = StrategyGuide::default();
+
+        for (opponent, column) in pairs {
+            let opponent = Hand::from_opponent_symbol(opponent).ok()?;
+            let column = COLUMNS
+                .iter()
+                .position(|candidate| candidate.eq_ignore_ascii_case(column))?;
+
+            guide.counts[opponent.index()][column] += 1;
+        }
+
+        Some(guide)
+    }
+
+    pub fn score(&self, mapping: &ColumnMapping) -> i32 {
+        let mut total = 0;
+
+        for opponent in Hand::ALL {
+            for (column, &count) in self.counts[opponent.index()].iter().enumerate() {
+                total += count * mapping.round(opponent, column).get_score();
+            }
+        }
+
+        total
+    }
+
+    pub fn evaluate(&self) -> Vec<Candidate> {
+        ColumnMapping::all()
+            .into_iter()
+            .map(|mapping| Candidate {
+                mapping,
+                score: self.score(&mapping),
+            })
+            .collect()
+    }
+
+    pub fn best(&self) -> Candidate {
+        self.evaluate()
+            .into_iter()
+            .reduce(|best, candidate| {
+                if candidate.score > best.score {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .expect("there are always candidate mappings")
+    }
+
+    pub fn worst(&self) -> Candidate {
+        self.evaluate()
+            .into_iter()
+            .reduce(|worst, candidate| {
+                if candidate.score < worst.score {
+                    candidate
+                } else {
+                    worst
+                }
+            })
+            .expect("there are always candidate mappings")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Candidate {
+    pub mapping: ColumnMapping,
+    pub score: i32,
+}
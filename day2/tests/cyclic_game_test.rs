@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+
+use day2::{cyclic_game::*, rock_paper_scissors::*};
+
+#[test]
+fn _definition_should_require_odd_number_of_unique_hands() {
+    assert_eq!(
+        CyclicGame::new(&["Rock", "Paper"]),
+        Err(GameDefinitionError::EvenHandCount(2))
+    );
+    assert_eq!(
+        CyclicGame::new(&[]),
+        Err(GameDefinitionError::EvenHandCount(0))
+    );
+    assert_eq!(
+        CyclicGame::new(&["Rock", "Paper", "rock"]),
+        Err(GameDefinitionError::DuplicateHand("rock".to_string()))
+    );
+    assert_eq!(
+        CyclicGame::with_scores(&["Rock", "Paper", "Scissors"], &[1, 2]),
+        Err(GameDefinitionError::ScoreCountMismatch {
+            hands: 3,
+            scores: 2
+        })
+    );
+}
+
+#[test]
+fn _derived_rock_paper_scissors_should_match_hand_ordering() {
+    let game = CyclicGame::rock_paper_scissors();
+    let hand_of = |hand| game.from_hand(hand).unwrap();
+
+    for hand in Hand::ALL {
+        for other in Hand::ALL {
+            assert_eq!(
+                game.compare(hand_of(hand), hand_of(other)),
+                Some(hand.cmp(&other)),
+                "{hand:?} vs {other:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn _derived_inverse_should_match_existing_function() {
+    let game = CyclicGame::rock_paper_scissors();
+    let hand_of = |hand| game.from_hand(hand).unwrap();
+
+    for opponent in Hand::ALL {
+        for result in [RoundResult::Lose, RoundResult::Draw, RoundResult::Win] {
+            let player = get_hand_from_opponent_and_result(&opponent, &result);
+            assert_eq!(
+                game.hands_for(hand_of(opponent), &result),
+                [hand_of(player)]
+            );
+        }
+    }
+}
+
+#[test]
+fn _rock_paper_scissors_lizard_spock_should_follow_the_rules() {
+    let game = CyclicGame::rock_paper_scissors_lizard_spock();
+    let hand = |name| game.hand(name).unwrap();
+
+    let wins = [
+        ("Scissors", "Paper"),
+        ("Paper", "Rock"),
+        ("Rock", "Lizard"),
+        ("Lizard", "Spock"),
+        ("Spock", "Scissors"),
+        ("Scissors", "Lizard"),
+        ("Lizard", "Paper"),
+        ("Paper", "Spock"),
+        ("Spock", "Rock"),
+        ("Rock", "Scissors"),
+    ];
+
+    for (winner, loser) in wins {
+        assert!(game.beats(hand(winner), hand(loser)), "{winner} vs {loser}");
+        assert!(
+            !game.beats(hand(loser), hand(winner)),
+            "{loser} vs {winner}"
+        );
+    }
+
+    for hand in game.hands() {
+        assert_eq!(game.compare(hand, hand), Some(Ordering::Equal));
+    }
+}
+
+#[test]
+fn _every_hand_should_beat_and_lose_to_half_of_the_others() {
+    let game = CyclicGame::new(&["a", "b", "c", "d", "e", "f", "g"]).unwrap();
+
+    for hand in game.hands() {
+        let wins = game
+            .hands()
+            .filter(|&other| game.beats(hand, other))
+            .count();
+        let losses = game
+            .hands()
+            .filter(|&other| game.beats(other, hand))
+            .count();
+        assert_eq!((wins, losses), (3, 3));
+    }
+}
+
+#[test]
+fn _inverse_should_list_every_matching_hand() {
+    let game = CyclicGame::rock_paper_scissors_lizard_spock();
+    let rock = game.hand("rock").unwrap();
+
+    let winners: Vec<&str> = game
+        .hands_for(rock, &RoundResult::Win)
+        .into_iter()
+        .filter_map(|hand| game.name(hand))
+        .collect();
+    assert_eq!(winners, ["Spock", "Paper"]);
+
+    for result in [RoundResult::Lose, RoundResult::Draw, RoundResult::Win] {
+        for hand in game.hands_for(rock, &result) {
+            assert_eq!(game.result(hand, rock), Some(result));
+        }
+    }
+}
+
+#[test]
+fn _score_should_add_hand_and_outcome_points() {
+    let game = CyclicGame::with_scores(&["Rock", "Paper", "Scissors"], &[10, 20, 30]).unwrap();
+    let rock = game.hand("Rock").unwrap();
+    let paper = game.hand("Paper").unwrap();
+
+    assert_eq!(game.score(rock, paper), Some(26));
+    assert_eq!(game.score(paper, rock), Some(10));
+    assert_eq!(game.score(rock, rock), Some(13));
+}
+
+#[test]
+fn _hands_from_other_games_should_be_rejected() {
+    let single = CyclicGame::new(&["a"]).unwrap();
+    let classic = CyclicGame::rock_paper_scissors();
+    let extended = CyclicGame::rock_paper_scissors_lizard_spock();
+
+    assert_eq!(single.from_hand(Hand::Scissors), None);
+    assert_eq!(
+        extended
+            .from_hand(Hand::Paper)
+            .and_then(|hand| extended.name(hand)),
+        Some("Paper")
+    );
+
+    let scissors = extended.hand("Scissors").unwrap();
+    let rock = classic.hand("Rock").unwrap();
+    assert_eq!(classic.name(scissors), None);
+    assert_eq!(classic.hand_score(scissors), None);
+    assert_eq!(classic.compare(scissors, rock), None);
+    assert!(!classic.beats(scissors, rock));
+    assert_eq!(classic.result(rock, scissors), None);
+    assert_eq!(classic.score(rock, scissors), None);
+    assert!(classic.hands_for(scissors, &RoundResult::Win).is_empty());
+}
+
+#[test]
+fn _in_range_hands_from_other_games_should_be_rejected() {
+    let classic = CyclicGame::rock_paper_scissors();
+    let extended = CyclicGame::rock_paper_scissors_lizard_spock();
+    let rescored = CyclicGame::with_scores(&["Rock", "Paper", "Scissors"], &[10, 20, 30]).unwrap();
+
+    let spock = extended.hand("Spock").unwrap();
+    let rock = classic.hand("Rock").unwrap();
+    assert!(spock.index() < classic.len());
+    assert_eq!(classic.name(spock), None);
+    assert_eq!(classic.hand_score(spock), None);
+    assert_eq!(classic.compare(spock, rock), None);
+    assert_eq!(classic.score(rock, spock), None);
+
+    let paper = rescored.hand("Paper").unwrap();
+    assert_eq!(classic.name(paper), None);
+    assert_eq!(
+        CyclicGame::rock_paper_scissors().name(classic.hand("Paper").unwrap()),
+        Some("Paper")
+    );
+}
@@ -0,0 +1,102 @@
+use day2::equilibrium::*;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn assert_strategy(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (&actual, &expected) in actual.iter().zip(expected) {
+        assert_close(actual, expected);
+    }
+}
+
+#[test]
+fn _should_reject_empty_or_ragged_matrices() {
+    assert_eq!(solve_zero_sum(&[]), None);
+    assert_eq!(solve_zero_sum(&[vec![]]), None);
+    assert_eq!(solve_zero_sum(&[vec![1.0, 2.0], vec![3.0]]), None);
+}
+
+#[test]
+fn _matching_pennies_should_mix_evenly() {
+    let equilibrium = solve_zero_sum(&[vec![1.0, -1.0], vec![-1.0, 1.0]]).unwrap();
+
+    assert_strategy(&equilibrium.row_strategy, &[0.5, 0.5]);
+    assert_strategy(&equilibrium.column_strategy, &[0.5, 0.5]);
+    assert_close(equilibrium.value, 0.0);
+}
+
+#[test]
+fn _dominant_row_should_be_played_purely() {
+    let equilibrium = solve_zero_sum(&[vec![3.0, 1.0], vec![2.0, 0.0]]).unwrap();
+
+    assert_strategy(&equilibrium.row_strategy, &[1.0, 0.0]);
+    assert_strategy(&equilibrium.column_strategy, &[0.0, 1.0]);
+    assert_close(equilibrium.value, 1.0);
+}
+
+#[test]
+fn _classic_rock_paper_scissors_should_mix_uniformly() {
+    let payoffs = vec![
+        vec![0.0, -1.0, 1.0],
+        vec![1.0, 0.0, -1.0],
+        vec![-1.0, 1.0, 0.0],
+    ];
+    let equilibrium = solve_zero_sum(&payoffs).unwrap();
+
+    assert_strategy(&equilibrium.row_strategy, &[1.0 / 3.0; 3]);
+    assert_strategy(&equilibrium.column_strategy, &[1.0 / 3.0; 3]);
+    assert_close(equilibrium.value, 0.0);
+}
+
+#[test]
+fn _score_payoffs_should_follow_round_scoring() {
+    assert_eq!(
+        score_payoffs(),
+        [
+            vec![4.0, 1.0, 7.0],
+            vec![8.0, 5.0, 2.0],
+            vec![3.0, 9.0, 6.0],
+        ]
+    );
+}
+
+#[test]
+fn _guaranteed_score_should_be_5_per_round() {
+    // Equalising every opponent column: 4a + 8b + 3c = a + 5b + 9c = 7a + 2b + 6c
+    // gives a = b = c = 1/3, worth (4 + 8 + 3) / 3 = 5.
+    let equilibrium = solve_zero_sum(&score_payoffs()).unwrap();
+
+    assert_strategy(&equilibrium.row_strategy, &[1.0 / 3.0; 3]);
+    assert_close(equilibrium.value, 5.0);
+}
+
+#[test]
+fn _score_difference_should_favour_rock_and_scissors() {
+    // Rows [0, -7, 4], [7, 0, -7], [-4, 7, 0]: equal columns need a = c and
+    // 7b = 4c, so a = c = 7/18 and b = 4/18 for a fair game.
+    let equilibrium = solve_zero_sum(&score_difference_payoffs()).unwrap();
+
+    assert_strategy(
+        &equilibrium.row_strategy,
+        &[7.0 / 18.0, 4.0 / 18.0, 7.0 / 18.0],
+    );
+    assert_strategy(
+        &equilibrium.column_strategy,
+        &[7.0 / 18.0, 4.0 / 18.0, 7.0 / 18.0],
+    );
+    assert_close(equilibrium.value, 0.0);
+}
+
+#[test]
+fn _custom_scoring_should_be_supported() {
+    let payoffs = payoff_matrix(|opponent, player| if player == opponent { 1.0 } else { 0.0 });
+    let equilibrium = solve_zero_sum(&payoffs).unwrap();
+
+    assert_strategy(&equilibrium.row_strategy, &[1.0 / 3.0; 3]);
+    assert_close(equilibrium.value, 1.0 / 3.0);
+}
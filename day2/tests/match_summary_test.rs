@@ -0,0 +1,62 @@
+use day2::{match_summary::*, rock_paper_scissors::*};
+
+fn example_rounds() -> Vec<Round> {
+    parse_rounds("A Y\nB X\nC Z\nA Z\nC X", str::parse).unwrap()
+}
+
+#[test]
+fn _empty_match_should_have_empty_summary() {
+    let summary = MatchSummary::from_rounds(&[]);
+
+    assert_eq!(summary.total(), HandSummary::default());
+    assert_eq!(summary.hand(Hand::Rock).rounds(), 0);
+}
+
+#[test]
+fn _should_aggregate_outcomes_per_played_hand() {
+    let summary = MatchSummary::from_rounds(&example_rounds());
+
+    assert_eq!(
+        *summary.hand(Hand::Rock),
+        HandSummary {
+            wins: 1,
+            draws: 0,
+            losses: 1,
+            shape_points: 2,
+            outcome_points: 6,
+        }
+    );
+    assert_eq!(
+        *summary.hand(Hand::Paper),
+        HandSummary {
+            wins: 1,
+            draws: 0,
+            losses: 0,
+            shape_points: 2,
+            outcome_points: 6,
+        }
+    );
+    assert_eq!(
+        *summary.hand(Hand::Scissors),
+        HandSummary {
+            wins: 0,
+            draws: 1,
+            losses: 1,
+            shape_points: 6,
+            outcome_points: 3,
+        }
+    );
+}
+
+#[test]
+fn _total_should_match_sum_of_round_scores() {
+    let rounds = example_rounds();
+    let summary = MatchSummary::from_rounds(&rounds);
+
+    let total = summary.total();
+    assert_eq!(total.rounds(), 5);
+    assert_eq!(
+        total.total_points(),
+        rounds.iter().map(Round::get_score).sum::<i32>()
+    );
+}
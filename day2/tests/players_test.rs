@@ -0,0 +1,84 @@
+use day2::{players::*, rock_paper_scissors::*};
+
+#[test]
+fn _fixed_guide_should_cycle_through_its_hands() {
+    let mut player = FixedGuide::new(vec![Hand::Rock, Hand::Scissors]).unwrap();
+
+    let hands: Vec<Hand> = (0..5).map(|_| player.choose()).collect();
+    assert_eq!(
+        hands,
+        [
+            Hand::Rock,
+            Hand::Scissors,
+            Hand::Rock,
+            Hand::Scissors,
+            Hand::Rock
+        ]
+    );
+}
+
+#[test]
+fn _empty_fixed_guide_should_be_rejected() {
+    assert!(FixedGuide::new(Vec::new()).is_none());
+}
+
+#[test]
+fn _uniform_random_should_be_reproducible_from_seed() {
+    let hands = |seed| {
+        let mut player = UniformRandom::new(seed);
+        (0..50).map(|_| player.choose()).collect::<Vec<Hand>>()
+    };
+
+    assert_eq!(hands(7), hands(7));
+    assert_ne!(hands(7), hands(8));
+}
+
+#[test]
+fn _uniform_random_should_play_every_hand_roughly_equally() {
+    let mut player = UniformRandom::new(2022);
+    let mut counts = [0; 3];
+    for _ in 0..3000 {
+        counts[player.choose().index()] += 1;
+    }
+
+    for count in counts {
+        assert!((900..1100).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+fn _frequency_counter_should_exploit_a_constant_opponent() {
+    let mut counter = FrequencyCounter::default();
+    let mut rock = FixedGuide::new(vec![Hand::Rock]).unwrap();
+
+    let report = play_match(&mut counter, &mut rock, 100);
+
+    assert_eq!(report.first_wins, 99);
+    assert_eq!(report.draws, 1);
+    assert_eq!(report.first_score, 99 * 8 + 4);
+    assert_eq!(report.second_score, 99 + 4);
+}
+
+#[test]
+fn _markov_chain_should_exploit_a_cycling_opponent() {
+    let mut markov = MarkovChain::default();
+    let mut cycle = FixedGuide::new(vec![Hand::Rock, Hand::Paper, Hand::Scissors]).unwrap();
+
+    let report = play_match(&mut markov, &mut cycle, 300);
+
+    assert!(report.first_wins >= 290, "{report:?}");
+}
+
+#[test]
+fn _match_report_should_account_for_every_round() {
+    let mut first = UniformRandom::new(1);
+    let mut second = MarkovChain::default();
+
+    let report = play_match(&mut first, &mut second, 250);
+
+    assert_eq!(report.rounds, 250);
+    assert_eq!(report.first_wins + report.second_wins + report.draws, 250);
+    let outcome_points = 6 * (report.first_wins + report.second_wins) + 6 * report.draws;
+    let hand_points = report.first_score + report.second_score - outcome_points as i32;
+    assert!((500..=1500).contains(&hand_points));
+}
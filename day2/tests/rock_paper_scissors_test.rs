@@ -57,3 +57,96 @@ fn _hands_should_convert_to_corresponding_scores() {
     assert_eq!(2, Hand::Paper as i32);
     assert_eq!(3, Hand::Scissors as i32);
 }
+
+#[test]
+fn _unknown_symbols_should_be_reported() {
+    assert_eq!(
+        "Q".parse::<Hand>(),
+        Err(ParseHandError::UnknownSymbol("Q".to_string()))
+    );
+    assert_eq!(
+        "A".parse::<RoundResult>(),
+        Err(ParseRoundResultError::UnknownSymbol("A".to_string()))
+    );
+}
+
+#[test]
+fn _rounds_should_parse_from_strings() {
+    assert_eq!("A Y".parse(), Ok(Round::new(Hand::Rock, Hand::Paper)));
+    assert_eq!(
+        Round::parse_with_result("A Y"),
+        Ok(Round::new(Hand::Rock, Hand::Rock))
+    );
+}
+
+#[test]
+fn _rounds_should_report_what_went_wrong() {
+    assert_eq!(
+        "A".parse::<Round>(),
+        Err(ParseRoundError::WrongTokenCount(1))
+    );
+    assert_eq!(
+        "A Y Z".parse::<Round>(),
+        Err(ParseRoundError::WrongTokenCount(3))
+    );
+    assert_eq!(
+        "D Y".parse::<Round>(),
+        Err(ParseRoundError::InvalidOpponent(
+            ParseHandError::UnknownSymbol("D".to_string())
+        ))
+    );
+    assert_eq!(
+        "A W".parse::<Round>(),
+        Err(ParseRoundError::InvalidPlayer(
+            ParseHandError::UnknownSymbol("W".to_string())
+        ))
+    );
+    assert_eq!(
+        Round::parse_with_result("A C"),
+        Err(ParseRoundError::InvalidResult(
+            ParseRoundResultError::UnknownSymbol("C".to_string())
+        ))
+    );
+}
+
+#[test]
+fn _parse_rounds_should_report_line_of_bad_round() {
+    let input = "A Y\nB X\n\nC Z\nC 7\nA X";
+
+    let error = parse_rounds(input, str::parse).unwrap_err();
+    assert_eq!(error.line, 5);
+    assert_eq!(
+        error.to_string(),
+        "Line 5: Invalid player: Unknown hand symbol: \"7\"."
+    );
+
+    let rounds = parse_rounds("A Y\nB X\n\nC Z\n", str::parse);
+    assert_eq!(rounds.map(|rounds| rounds.len()), Ok(3));
+}
+
+#[test]
+fn _score_breakdown_should_separate_shape_and_outcome_points() {
+    let round = Round::new(Hand::Rock, Hand::Paper);
+    assert_eq!(
+        round.get_score_breakdown(),
+        ScoreBreakdown {
+            shape: 2,
+            outcome: 6,
+            result: RoundResult::Win
+        }
+    );
+
+    let round = Round::new(Hand::Paper, Hand::Rock);
+    assert_eq!(
+        round.get_score_breakdown(),
+        ScoreBreakdown {
+            shape: 1,
+            outcome: 0,
+            result: RoundResult::Lose
+        }
+    );
+
+    let round = Round::new(Hand::Scissors, Hand::Scissors);
+    assert_eq!(round.get_score_breakdown().total(), 6);
+    assert_eq!(round.get_score(), 6);
+}
@@ -0,0 +1,137 @@
+use day2::{rock_paper_scissors::*, round_decoder::*, strategy_optimiser::*};
+
+const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+fn total_score(decoder: &impl RoundDecoder) -> Result<i32, ParseRoundsError> {
+    Ok(parse_rounds(EXAMPLE, |line| decoder.decode_line(line))?
+        .iter()
+        .map(Round::get_score)
+        .sum())
+}
+
+#[test]
+fn _built_in_decoders_should_match_puzzle_interpretations() {
+    assert_eq!(total_score(&HandDecoder), Ok(15));
+    assert_eq!(total_score(&ResultDecoder), Ok(12));
+}
+
+#[test]
+fn _column_mappings_should_decode_rounds() {
+    let mapping = ColumnMapping::Hands([Hand::Scissors, Hand::Paper, Hand::Rock]);
+
+    assert_eq!(total_score(&mapping), Ok(24));
+    assert!(mapping.decode("X", "Y").is_err());
+}
+
+#[test]
+fn _column_mappings_should_report_unknown_columns_by_kind() {
+    let hands = ColumnMapping::Hands(Hand::ALL);
+    let results = ColumnMapping::Results([RoundResult::Lose, RoundResult::Draw, RoundResult::Win]);
+
+    assert_eq!(
+        hands.decode("A", "W"),
+        Err(ParseRoundError::InvalidPlayer(
+            ParseHandError::UnknownSymbol("W".to_string())
+        ))
+    );
+    assert_eq!(
+        results.decode("A", "W"),
+        Err(ParseRoundError::InvalidResult(
+            ParseRoundResultError::UnknownSymbol("W".to_string())
+        ))
+    );
+    assert_eq!(
+        results.decode("X", "Y"),
+        Err(ParseRoundError::InvalidOpponent(
+            ParseHandError::UnknownSymbol("X".to_string())
+        ))
+    );
+}
+
+#[test]
+fn _config_should_define_custom_mapping() -> Result<(), ParseDecoderError> {
+    let decoder: MappingDecoder = "
+        # opponent symbols
+        opponent.A = Rock
+        opponent.B = Paper
+        opponent.C = Scissors
+
+        player.X = win   # mixed hands and results
+        player.Y = Rock
+        player.Z = lose
+    "
+    .parse()?;
+
+    assert_eq!(
+        decoder.decode("A", "X"),
+        Ok(Round::new(Hand::Rock, Hand::Paper))
+    );
+    assert_eq!(
+        decoder.decode("c", "y"),
+        Ok(Round::new(Hand::Scissors, Hand::Rock))
+    );
+    assert_eq!(
+        decoder.decode_line("B Z"),
+        Ok(Round::new(Hand::Paper, Hand::Rock))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn _custom_mapping_should_reject_unmapped_symbols() {
+    let decoder: MappingDecoder = "opponent.A = Rock\nplayer.X = Draw".parse().unwrap();
+
+    assert_eq!(
+        decoder.decode("B", "X"),
+        Err(ParseRoundError::InvalidOpponent(
+            ParseHandError::UnknownSymbol("B".to_string())
+        ))
+    );
+    assert_eq!(
+        decoder.decode("A", "Y"),
+        Err(ParseRoundError::InvalidPlayer(
+            ParseHandError::UnknownSymbol("Y".to_string())
+        ))
+    );
+}
+
+#[test]
+fn _column_targets_should_parse_hands_and_results() {
+    assert_eq!("Paper".parse(), Ok(ColumnTarget::Hand(Hand::Paper)));
+    assert_eq!("LOSE".parse(), Ok(ColumnTarget::Result(RoundResult::Lose)));
+    assert_eq!(
+        "Spock".parse::<ColumnTarget>(),
+        Err(ParseColumnTargetError::UnknownTarget("Spock".to_string()))
+    );
+}
+
+#[test]
+fn _config_errors_should_report_line() {
+    assert_eq!(
+        "opponent.A = Rock\nplayer.X Rock".parse::<MappingDecoder>(),
+        Err(ParseDecoderError::MissingEquals { line: 2 })
+    );
+    assert_eq!(
+        "referee.A = Rock".parse::<MappingDecoder>(),
+        Err(ParseDecoderError::UnknownColumn {
+            line: 1,
+            column: "referee".to_string()
+        })
+    );
+    assert_eq!(
+        "\nplayer.X = Spock".parse::<MappingDecoder>(),
+        Err(ParseDecoderError::UnknownTarget {
+            line: 2,
+            error: ParseColumnTargetError::UnknownTarget("Spock".to_string())
+        })
+    );
+    assert_eq!(
+        "opponent.A = Rock\nplayerX = rock".parse::<MappingDecoder>(),
+        Err(ParseDecoderError::MissingColumn { line: 2 })
+    );
+    assert_eq!(
+        "opponent.A = Win".parse::<MappingDecoder>(),
+        Err(ParseDecoderError::ResultForOpponent { line: 1 })
+    );
+}
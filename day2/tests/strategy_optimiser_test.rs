@@ -0,0 +1,81 @@
+use day2::{rock_paper_scissors::*, strategy_optimiser::*};
+
+const EXAMPLE: [(&str, &str); 3] = [("A", "Y"), ("B", "X"), ("C", "Z")];
+
+#[test]
+fn _should_consider_every_hand_and_result_mapping() {
+    let mappings = ColumnMapping::all();
+
+    assert_eq!(mappings.len(), 12);
+    assert_eq!(
+        mappings
+            .iter()
+            .filter(|mapping| matches!(mapping, ColumnMapping::Hands(_)))
+            .count(),
+        6
+    );
+}
+
+#[test]
+fn _should_reproduce_puzzle_interpretations() {
+    let guide = StrategyGuide::from_pairs(&EXAMPLE).unwrap();
+
+    let as_hands = ColumnMapping::Hands([Hand::Rock, Hand::Paper, Hand::Scissors]);
+    assert_eq!(guide.score(&as_hands), 15);
+
+    let as_results =
+        ColumnMapping::Results([RoundResult::Lose, RoundResult::Draw, RoundResult::Win]);
+    assert_eq!(guide.score(&as_results), 12);
+}
+
+#[test]
+fn _should_report_score_for_each_candidate() {
+    let guide = StrategyGuide::from_pairs(&EXAMPLE).unwrap();
+
+    for candidate in guide.evaluate() {
+        let expected: i32 = EXAMPLE
+            .iter()
+            .map(|(opponent, column)| {
+                let column = ["X", "Y", "Z"].iter().position(|c| c == column).unwrap();
+                candidate
+                    .mapping
+                    .round(opponent.parse().unwrap(), column)
+                    .get_score()
+            })
+            .sum();
+        assert_eq!(candidate.score, expected, "{}", candidate.mapping);
+    }
+}
+
+#[test]
+fn _should_find_best_and_worst_mappings() {
+    let guide = StrategyGuide::from_pairs(&EXAMPLE).unwrap();
+
+    assert_eq!(
+        guide.best(),
+        Candidate {
+            mapping: ColumnMapping::Hands([Hand::Scissors, Hand::Paper, Hand::Rock]),
+            score: 24,
+        }
+    );
+    assert_eq!(
+        guide.worst(),
+        Candidate {
+            mapping: ColumnMapping::Hands([Hand::Rock, Hand::Scissors, Hand::Paper]),
+            score: 6,
+        }
+    );
+}
+
+#[test]
+fn _should_reject_unknown_columns() {
+    assert!(StrategyGuide::from_pairs(&[("A", "W")]).is_none());
+    assert!(StrategyGuide::from_pairs(&[("X", "Y")]).is_none());
+}
+
+#[test]
+fn _mapping_should_display_column_assignments() {
+    let mapping = ColumnMapping::Results([RoundResult::Lose, RoundResult::Draw, RoundResult::Win]);
+
+    assert_eq!(mapping.to_string(), "X=Lose Y=Draw Z=Win");
+}
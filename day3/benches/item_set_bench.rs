@@ -0,0 +1,81 @@
+use std::collections::{HashMap, HashSet};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day3::rucksack_reorganization::{common_items, get_priority, pair_line};
+
+const INPUT: &str = include_str!("../data/day3.txt");
+
+// The HashSet/HashMap intersection that `common_items` replaced, kept as a baseline.
+fn hashed_commonality(item_groups: &[&str]) -> String {
+    let mut items: HashMap<char, usize> = HashMap::new();
+
+    for group in item_groups {
+        let group: HashSet<char> = group.chars().collect();
+        for item in group {
+            *items.entry(item).or_insert(0) += 1;
+        }
+    }
+
+    items
+        .iter()
+        .filter(|(_, &count)| count == item_groups.len())
+        .map(|(item, _)| item)
+        .collect()
+}
+
+fn hashed_total(lines: &[&str]) -> u32 {
+    let pairs: u32 = lines
+        .iter()
+        .map(|line| {
+            let (comp1, comp2) = pair_line(line);
+            get_priority(&hashed_commonality(&[comp1, comp2])).unwrap_or_default()
+        })
+        .sum();
+    let groups: u32 = lines
+        .chunks(3)
+        .map(|group| get_priority(&hashed_commonality(group)).unwrap_or_default())
+        .sum();
+
+    pairs + groups
+}
+
+fn bitset_total(lines: &[&str]) -> u32 {
+    let pairs: u32 = lines
+        .iter()
+        .map(|line| {
+            let (comp1, comp2) = pair_line(line);
+            common_items(&[comp1, comp2]).priority_sum()
+        })
+        .sum();
+    let groups: u32 = lines
+        .chunks(3)
+        .map(|group| common_items(group).priority_sum())
+        .sum();
+
+    pairs + groups
+}
+
+fn commonality(c: &mut Criterion) {
+    let puzzle: Vec<&str> = INPUT.lines().collect();
+    let mut group = c.benchmark_group("commonality");
+
+    for copies in [1, 10, 100] {
+        let lines: Vec<&str> = puzzle.repeat(copies);
+
+        group.bench_with_input(
+            BenchmarkId::new("hash_set", lines.len()),
+            &lines,
+            |b, lines| b.iter(|| hashed_total(black_box(lines))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("item_set", lines.len()),
+            &lines,
+            |b, lines| b.iter(|| bitset_total(black_box(lines))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, commonality);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseItemError(pub char);
+
+impl Display for ParseItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not an item, expected a-z or A-Z.", self.0)
+    }
+}
+
+impl Error for ParseItemError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Item(char);
+
+impl Item {
+    pub fn new(symbol: char) -> Result<Self, ParseItemError> {
+        if symbol.is_ascii_alphabetic() {
+            Ok(Item(symbol))
+        } else {
+            Err(ParseItemError(symbol))
+        }
+    }
+
+    pub fn from_priority(priority: u32) -> Option<Self> {
+        let symbol = match priority {
+            1..=26 => b'a' + (priority - 1) as u8,
+            27..=52 => b'A' + (priority - 27) as u8,
+            _ => return None,
+        };
+
+        Some(Item(char::from(symbol)))
+    }
+
+    pub fn symbol(&self) -> char {
+        self.0
+    }
+
+    pub fn priority(&self) -> u32 {
+        match self.0 {
+            'a'..='z' => self.0 as u32 - 'a' as u32 + 1,
+            _ => self.0 as u32 - 'A' as u32 + 27,
+        }
+    }
+}
+
+impl TryFrom<char> for Item {
+    type Error = ParseItemError;
+
+    fn try_from(symbol: char) -> Result<Self, Self::Error> {
+        Item::new(symbol)
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
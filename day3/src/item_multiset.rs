@@ -0,0 +1,121 @@
+use crate::{item::Item, item_set::ItemSet};
+
+const ITEM_KINDS: usize = 52;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ItemMultiset {
+    counts: [usize; ITEM_KINDS],
+}
+
+impl Default for ItemMultiset {
+    fn default() -> Self {
+        ItemMultiset {
+            counts: [0; ITEM_KINDS],
+        }
+    }
+}
+
+impl ItemMultiset {
+    pub fn from_items(items: &str) -> Self {
+        items.chars().collect()
+    }
+
+    pub fn insert(&mut self, item: char) -> bool {
+        match Item::new(item) {
+            Ok(item) => {
+                self.counts[item.priority() as usize - 1] += 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn count(&self, item: char) -> usize {
+        Item::new(item).map_or(0, |item| self.counts[item.priority() as usize - 1])
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|&count| count == 0)
+    }
+
+    pub fn distinct(&self) -> ItemSet {
+        self.at_least(1)
+    }
+
+    pub fn at_least(&self, min_count: usize) -> ItemSet {
+        self.iter()
+            .filter(|&(_, count)| count >= min_count.max(1))
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &ItemMultiset) -> ItemMultiset {
+        self.combine(other, usize::min)
+    }
+
+    pub fn union(&self, other: &ItemMultiset) -> ItemMultiset {
+        self.combine(other, usize::max)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Item, usize)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .filter_map(|(index, &count)| Some((Item::from_priority(index as u32 + 1)?, count)))
+    }
+
+    fn combine(&self, other: &ItemMultiset, op: impl Fn(usize, usize) -> usize) -> ItemMultiset {
+        let mut result = ItemMultiset::default();
+
+        for (index, count) in result.counts.iter_mut().enumerate() {
+            *count = op(self.counts[index], other.counts[index]);
+        }
+
+        result
+    }
+}
+
+impl FromIterator<char> for ItemMultiset {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut multiset = ItemMultiset::default();
+
+        for item in iter {
+            multiset.insert(item);
+        }
+
+        multiset
+    }
+}
+
+pub fn k_way_intersection(groups: &[&str], min_groups: usize) -> ItemMultiset {
+    // Each item keeps its `min_groups`-th highest count, the largest multiplicity shared by at
+    // least that many groups. Requiring every group gives the usual multiset intersection.
+    let mut result = ItemMultiset::default();
+    if min_groups == 0 || min_groups > groups.len() {
+        return result;
+    }
+
+    let multisets: Vec<ItemMultiset> = groups
+        .iter()
+        .map(|group| ItemMultiset::from_items(group))
+        .collect();
+
+    let mut counts = Vec::with_capacity(multisets.len());
+    for (index, count) in result.counts.iter_mut().enumerate() {
+        counts.clear();
+        counts.extend(multisets.iter().map(|multiset| multiset.counts[index]));
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        *count = counts[min_groups - 1];
+    }
+
+    result
+}
+
+pub fn items_in_at_least(groups: &[&str], min_groups: usize, min_count: usize) -> ItemSet {
+    k_way_intersection(groups, min_groups).at_least(min_count)
+}
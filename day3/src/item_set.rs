@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use crate::item::Item;
+
+const ITEM_KINDS: u32 = 52;
+
+fn item_bit(item: char) -> Option<u32> {
+    Item::new(item).ok().map(|item| item.priority() - 1)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << ITEM_KINDS) - 1);
+
+    pub fn from_items(items: &str) -> Self {
+        items.chars().collect()
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn insert(&mut self, item: char) -> bool {
+        match item_bit(item) {
+            Some(bit) => {
+                let inserted = self.0 & (1 << bit) == 0;
+                self.0 |= 1 << bit;
+                inserted
+            }
+            None => false,
+        }
+    }
+
+    pub fn insert_item(&mut self, item: Item) -> bool {
+        self.insert(item.symbol())
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        item_bit(item).is_some_and(|bit| self.0 & (1 << bit) != 0)
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        let mut bits = self.0;
+        let mut total = 0;
+
+        while bits != 0 {
+            total += bits.trailing_zeros() + 1;
+            bits &= bits - 1;
+        }
+
+        total
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = Item> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+
+            let bit = bits.trailing_zeros();
+            bits &= bits - 1;
+            Item::from_priority(bit + 1)
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        self.items().map(|item| item.symbol())
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut set = ItemSet::EMPTY;
+
+        for item in iter {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
+        let mut set = ItemSet::EMPTY;
+
+        for item in iter {
+            set.insert_item(item);
+        }
+
+        set
+    }
+}
+
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in self.iter() {
+            write!(f, "{item}")?;
+        }
+
+        Ok(())
+    }
+}
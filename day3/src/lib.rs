@@ -1 +1,5 @@
+pub mod item;
+pub mod item_multiset;
+pub mod item_set;
+pub mod repacking;
 pub mod rucksack_reorganization;
@@ -0,0 +1,130 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{item::Item, item_set::ItemSet, rucksack_reorganization::Rucksack};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RepackError {
+    pub rucksack: usize,
+}
+
+impl Display for RepackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Rucksack {} cannot be split into two equal compartments without sharing an item type.",
+            self.rucksack
+        )
+    }
+}
+
+impl Error for RepackError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repacking {
+    pub moves: usize,
+    pub rucksack: Rucksack,
+}
+
+struct ItemCounts {
+    item: Item,
+    first: usize,
+    second: usize,
+}
+
+fn count_items(rucksack: &Rucksack) -> Vec<ItemCounts> {
+    let mut counts: Vec<ItemCounts> = Vec::new();
+    let (first, second) = rucksack.compartments();
+
+    for (items, in_first) in [(first, true), (second, false)] {
+        for &item in items {
+            let index = match counts.iter().position(|counts| counts.item == item) {
+                Some(index) => index,
+                None => {
+                    counts.push(ItemCounts {
+                        item,
+                        first: 0,
+                        second: 0,
+                    });
+                    counts.len() - 1
+                }
+            };
+
+            if in_first {
+                counts[index].first += 1;
+            } else {
+                counts[index].second += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+pub fn repack(rucksack: &Rucksack) -> Option<Repacking> {
+    let counts = count_items(rucksack);
+    let half = rucksack.items().len() / 2;
+
+    // costs[i][size] is the fewest moves placing the first i item types so that
+    // `size` items end up in the first compartment.
+    let mut costs = vec![vec![None; half + 1]; counts.len() + 1];
+    costs[0][0] = Some(0);
+
+    for (index, counts) in counts.iter().enumerate() {
+        let total = counts.first + counts.second;
+
+        for size in 0..=half {
+            let Some(cost) = costs[index][size] else {
+                continue;
+            };
+
+            let mut relax = |size: usize, cost: usize| {
+                let best: &mut Option<usize> = &mut costs[index + 1][size];
+                if best.is_none_or(|best| cost < best) {
+                    *best = Some(cost);
+                }
+            };
+
+            relax(size, cost + counts.first);
+            if size + total <= half {
+                relax(size + total, cost + counts.second);
+            }
+        }
+    }
+
+    let moves = costs[counts.len()][half]?;
+
+    let mut kept_first = ItemSet::EMPTY;
+    let mut size = half;
+    for index in (0..counts.len()).rev() {
+        let total = counts[index].first + counts[index].second;
+        let cost = costs[index + 1][size];
+
+        if size >= total && costs[index][size - total].map(|c| c + counts[index].second) == cost {
+            kept_first.insert_item(counts[index].item);
+            size -= total;
+        }
+    }
+
+    let keeps_first = |item: Item| kept_first.contains(item.symbol());
+
+    let mut items = rucksack.items().to_vec();
+    let (first, second) = items.split_at_mut(half);
+    let leaving_first = first.iter_mut().filter(|item| !keeps_first(**item));
+    let leaving_second = second.iter_mut().filter(|item| keeps_first(**item));
+    for (left, right) in leaving_first.zip(leaving_second) {
+        std::mem::swap(left, right);
+    }
+
+    Some(Repacking {
+        moves,
+        rucksack: Rucksack::new(items).ok()?,
+    })
+}
+
+pub fn repack_all(rucksacks: &[Rucksack]) -> Result<Vec<Repacking>, RepackError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(index, rucksack)| repack(rucksack).ok_or(RepackError { rucksack: index }))
+        .collect()
+}
@@ -1,54 +1,224 @@
-use std::collections::{HashMap, HashSet};
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{
+    item::{Item, ParseItemError},
+    item_set::ItemSet,
+};
 
 pub fn pair_line(line: &str) -> (&str, &str) {
     line.split_at(line.len() / 2)
 }
 
-fn remove_duplicate_items(items: &str) -> String {
-    let mut result = HashSet::new();
+pub fn common_items(item_groups: &[&str]) -> ItemSet {
+    let mut groups = item_groups.iter().map(|group| ItemSet::from_items(group));
 
-    for item in items.chars() {
-        result.insert(item);
+    match groups.next() {
+        Some(first) => groups.fold(first, |common, group| common.intersection(&group)),
+        None => ItemSet::EMPTY,
     }
-
-    result.iter().collect()
 }
 
 pub fn find_commonality(item_groups: &[&str]) -> String {
-    let mut items: HashMap<char, usize> = HashMap::new();
+    common_items(item_groups).to_string()
+}
 
-    let group_count = item_groups.len();
+pub fn get_priority(items: &str) -> Result<u32, ParseItemError> {
+    items
+        .chars()
+        .map(|item| Item::new(item).map(|item| item.priority()))
+        .sum()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseRucksackError {
+    InvalidItem {
+        position: usize,
+        error: ParseItemError,
+    },
+    UnevenCompartments(usize),
+}
 
-    let item_groups = item_groups
-        .iter()
-        .map(|group| remove_duplicate_items(group));
+impl Display for ParseRucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRucksackError::InvalidItem { position, error } => {
+                write!(f, "Item {position}: {error}")
+            }
+            ParseRucksackError::UnevenCompartments(count) => {
+                write!(
+                    f,
+                    "{count} items cannot be split into two equal compartments."
+                )
+            }
+        }
+    }
+}
 
-    for item_group in item_groups {
-        for item in item_group.chars() {
-            items
-                .entry(item)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
+impl Error for ParseRucksackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseRucksackError::InvalidItem { error, .. } => Some(error),
+            ParseRucksackError::UnevenCompartments(_) => None,
         }
     }
+}
 
-    items
-        .iter()
-        .filter(|(_, &count)| count == group_count)
-        .map(|(item, _)| item)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRucksacksError {
+    pub line: usize,
+    pub error: ParseRucksackError,
+}
+
+impl Display for ParseRucksacksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.error)
+    }
+}
+
+impl Error for ParseRucksacksError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    items: Vec<Item>,
+}
+
+impl Rucksack {
+    pub fn new(items: Vec<Item>) -> Result<Self, ParseRucksackError> {
+        if !items.len().is_multiple_of(2) {
+            return Err(ParseRucksackError::UnevenCompartments(items.len()));
+        }
+
+        Ok(Rucksack { items })
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    pub fn compartments(&self) -> (&[Item], &[Item]) {
+        self.items.split_at(self.items.len() / 2)
+    }
+
+    pub fn item_set(&self) -> ItemSet {
+        self.items.iter().copied().collect()
+    }
+
+    pub fn shared_items(&self) -> ItemSet {
+        let (first, second) = self.compartments();
+        let first: ItemSet = first.iter().copied().collect();
+        let second: ItemSet = second.iter().copied().collect();
+
+        first.intersection(&second)
+    }
+}
+
+impl Display for Rucksack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.items {
+            write!(f, "{item}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseRucksackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = s
+            .chars()
+            .enumerate()
+            .map(|(index, item)| {
+                Item::new(item).map_err(|error| ParseRucksackError::InvalidItem {
+                    position: index + 1,
+                    error,
+                })
+            })
+            .collect::<Result<Vec<Item>, ParseRucksackError>>()?;
+
+        Rucksack::new(items)
+    }
+}
+
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseRucksacksError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.trim().parse().map_err(|error| ParseRucksacksError {
+                line: index + 1,
+                error,
+            })
+        })
         .collect()
 }
 
-pub fn get_priority(items: &str) -> i32 {
-    let mut total = 0;
+#[derive(Debug, PartialEq, Eq)]
+pub enum BadgeError {
+    ZeroGroupSize,
+    IncompleteGroup { group: usize, size: usize },
+    NoBadge { group: usize },
+    MultipleBadges { group: usize, items: ItemSet },
+}
 
-    for item in items.chars() {
-        if item.is_lowercase() {
-            total += item as i32 - 96;
-        } else if item.is_uppercase() {
-            total += item as i32 - 38;
+impl Display for BadgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BadgeError::ZeroGroupSize => write!(f, "Groups must contain at least one rucksack."),
+            BadgeError::IncompleteGroup { group, size } => {
+                write!(f, "Group {group} only has {size} rucksacks.")
+            }
+            BadgeError::NoBadge { group } => write!(f, "Group {group} has no common item."),
+            BadgeError::MultipleBadges { group, items } => {
+                write!(f, "Group {group} has several common items: {items}.")
+            }
         }
     }
+}
+
+impl Error for BadgeError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Badge {
+    pub group: usize,
+    pub item: Item,
+}
+
+pub fn group_badges(rucksacks: &[Rucksack], group_size: usize) -> Result<Vec<Badge>, BadgeError> {
+    if group_size == 0 {
+        return Err(BadgeError::ZeroGroupSize);
+    }
+    if !rucksacks.len().is_multiple_of(group_size) {
+        return Err(BadgeError::IncompleteGroup {
+            group: rucksacks.len() / group_size,
+            size: rucksacks.len() % group_size,
+        });
+    }
+
+    rucksacks
+        .chunks(group_size)
+        .enumerate()
+        .map(|(group, members)| {
+            let common = members
+                .iter()
+                .map(Rucksack::item_set)
+                .fold(ItemSet::ALL, |common, items| common.intersection(&items));
 
-    total
+            let mut items = common.items();
+            match (items.next(), items.next()) {
+                (Some(item), None) => Ok(Badge { group, item }),
+                (None, _) => Err(BadgeError::NoBadge { group }),
+                (Some(_), Some(_)) => Err(BadgeError::MultipleBadges {
+                    group,
+                    items: common,
+                }),
+            }
+        })
+        .collect()
 }
@@ -0,0 +1,62 @@
+use day3::{item_multiset::*, item_set::ItemSet};
+
+#[test]
+fn _multiset_should_count_items() {
+    let multiset = ItemMultiset::from_items("aabZZZ-");
+
+    assert_eq!(multiset.count('a'), 2);
+    assert_eq!(multiset.count('b'), 1);
+    assert_eq!(multiset.count('Z'), 3);
+    assert_eq!(multiset.count('c'), 0);
+    assert_eq!(multiset.count('-'), 0);
+    assert_eq!(multiset.len(), 6);
+    assert_eq!(multiset.distinct(), ItemSet::from_items("abZ"));
+    assert_eq!(multiset.at_least(2), ItemSet::from_items("aZ"));
+    assert!(ItemMultiset::default().is_empty());
+}
+
+#[test]
+fn _multisets_should_combine_counts() {
+    let left = ItemMultiset::from_items("aaabc");
+    let right = ItemMultiset::from_items("abbd");
+
+    assert_eq!(left.intersection(&right), ItemMultiset::from_items("ab"));
+    assert_eq!(left.union(&right), ItemMultiset::from_items("aaabbcd"));
+}
+
+#[test]
+fn _k_way_intersection_over_all_groups_should_keep_minimum_counts() {
+    let groups = ["aabbc", "abbbc", "bbcc"];
+    let common = k_way_intersection(&groups, 3);
+
+    assert_eq!(common, ItemMultiset::from_items("bbc"));
+    assert_eq!(common.distinct().to_string(), "bc");
+    assert_eq!(items_in_at_least(&groups, 3, 2), ItemSet::from_items("b"));
+}
+
+#[test]
+fn _k_way_intersection_should_apply_group_threshold() {
+    let groups = ["aaxy", "aaay", "bz", "yy"];
+
+    assert_eq!(
+        k_way_intersection(&groups, 2),
+        ItemMultiset::from_items("aay")
+    );
+    assert_eq!(
+        k_way_intersection(&groups, 1),
+        ItemMultiset::from_items("aaabxyyz")
+    );
+    assert_eq!(items_in_at_least(&groups, 2, 1), ItemSet::from_items("ay"));
+    assert_eq!(items_in_at_least(&groups, 3, 1), ItemSet::from_items("y"));
+    assert_eq!(items_in_at_least(&groups, 2, 2), ItemSet::from_items("a"));
+    assert_eq!(items_in_at_least(&groups, 2, 3), ItemSet::EMPTY);
+}
+
+#[test]
+fn _k_way_intersection_should_reject_impossible_thresholds() {
+    let groups = ["ab", "bc"];
+
+    assert!(k_way_intersection(&groups, 0).is_empty());
+    assert!(k_way_intersection(&groups, 3).is_empty());
+    assert!(k_way_intersection(&[], 1).is_empty());
+}
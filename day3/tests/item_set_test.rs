@@ -0,0 +1,72 @@
+use day3::{item_set::*, rucksack_reorganization::*};
+
+const EXAMPLE: [&str; 6] = [
+    "vJrwpWtwJgWrhcsFMMfFFhFp",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+    "PmmdzqPrVvPwwTWBwg",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+    "ttgJtRGJQctTZtZT",
+    "CrZsJsPPZsGzwwsLwLmpwMDw",
+];
+
+#[test]
+fn _item_set_should_map_items_to_priorities() {
+    assert_eq!(ItemSet::from_items("a").priority_sum(), 1);
+    assert_eq!(ItemSet::from_items("z").priority_sum(), 26);
+    assert_eq!(ItemSet::from_items("A").priority_sum(), 27);
+    assert_eq!(ItemSet::from_items("Z").priority_sum(), 52);
+    assert_eq!(ItemSet::ALL.priority_sum(), (1..=52).sum());
+}
+
+#[test]
+fn _item_set_should_ignore_duplicates_and_non_items() {
+    let mut set = ItemSet::from_items("aab1 B");
+
+    assert_eq!(set.len(), 3);
+    assert!(set.contains('a'));
+    assert!(set.contains('B'));
+    assert!(!set.contains('1'));
+    assert!(!set.insert('a'));
+    assert!(set.insert('Z'));
+    assert!(!set.insert('?'));
+    assert_eq!(set.to_string(), "abBZ");
+}
+
+#[test]
+fn _item_set_should_combine_sets() {
+    let left = ItemSet::from_items("abcX");
+    let right = ItemSet::from_items("bcdY");
+
+    assert_eq!(left.intersection(&right), ItemSet::from_items("bc"));
+    assert_eq!(left.union(&right), ItemSet::from_items("abcdXY"));
+    assert!(left.intersection(&ItemSet::EMPTY).is_empty());
+    assert_eq!(left.union(&ItemSet::EMPTY), left);
+}
+
+#[test]
+fn _compartments_should_share_one_item() {
+    let shared: Vec<String> = EXAMPLE
+        .iter()
+        .map(|line| {
+            let (comp1, comp2) = pair_line(line);
+            find_commonality(&[comp1, comp2])
+        })
+        .collect();
+
+    assert_eq!(shared, ["p", "L", "P", "v", "t", "s"]);
+}
+
+#[test]
+fn _groups_should_share_their_badge() {
+    let badges: Vec<ItemSet> = EXAMPLE.chunks(3).map(common_items).collect();
+
+    assert_eq!(badges[0].to_string(), "r");
+    assert_eq!(badges[1].to_string(), "Z");
+    assert_eq!(badges.iter().map(ItemSet::priority_sum).sum::<u32>(), 70);
+}
+
+#[test]
+fn _no_groups_should_have_nothing_in_common() {
+    assert!(common_items(&[]).is_empty());
+    assert_eq!(find_commonality(&[]), "");
+}
@@ -0,0 +1,69 @@
+use day3::{item_set::ItemSet, repacking::*, rucksack_reorganization::*};
+
+fn repack_line(line: &str) -> Option<(usize, String)> {
+    let rucksack: Rucksack = line.parse().unwrap();
+    repack(&rucksack).map(|repacking| (repacking.moves, repacking.rucksack.to_string()))
+}
+
+fn is_disjoint(rucksack: &Rucksack) -> bool {
+    rucksack.shared_items() == ItemSet::EMPTY
+}
+
+#[test]
+fn _disjoint_rucksacks_should_not_move() {
+    assert_eq!(repack_line("abcdef"), Some((0, "abcdef".to_string())));
+    assert_eq!(repack_line(""), Some((0, String::new())));
+}
+
+#[test]
+fn _shared_items_should_swap_compartments() {
+    assert_eq!(repack_line("abbc"), Some((2, "acbb".to_string())));
+    assert_eq!(repack_line("aabcab"), Some((2, "aaacbb".to_string())));
+}
+
+#[test]
+fn _repacking_should_minimise_moves() {
+    // Keeping `x` in the first compartment costs one swap, moving it costs three.
+    let (moves, rucksack) = repack_line("xxxaxbcd").unwrap();
+
+    assert_eq!(moves, 2);
+    assert_eq!(rucksack, "xxxxabcd");
+}
+
+#[test]
+fn _unbalanceable_rucksacks_should_be_rejected() {
+    assert_eq!(repack_line("aaab"), None);
+
+    let rucksacks = parse_rucksacks("abcd\naaab").unwrap();
+    assert_eq!(repack_all(&rucksacks), Err(RepackError { rucksack: 1 }));
+}
+
+#[test]
+fn _repacked_puzzle_example_should_share_nothing() {
+    let rucksacks = parse_rucksacks(
+        "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+    )
+    .unwrap();
+
+    let repackings = repack_all(&rucksacks).unwrap();
+
+    for (original, repacking) in rucksacks.iter().zip(&repackings) {
+        assert!(is_disjoint(&repacking.rucksack));
+        assert_eq!(original.item_set(), repacking.rucksack.item_set());
+
+        let changed = original
+            .items()
+            .iter()
+            .zip(repacking.rucksack.items())
+            .filter(|(before, after)| before != after)
+            .count();
+        assert!(changed <= repacking.moves);
+    }
+    // `p` appears once in each compartment of the first rucksack.
+    assert_eq!(repackings[0].moves, 2);
+}
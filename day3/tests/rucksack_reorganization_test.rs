@@ -0,0 +1,144 @@
+use day3::{item::*, rucksack_reorganization::*};
+
+#[test]
+fn _items_should_have_priorities() {
+    assert_eq!(Item::new('a').map(|item| item.priority()), Ok(1));
+    assert_eq!(Item::new('z').map(|item| item.priority()), Ok(26));
+    assert_eq!(Item::new('A').map(|item| item.priority()), Ok(27));
+    assert_eq!(Item::new('Z').map(|item| item.priority()), Ok(52));
+
+    for priority in 1..=52 {
+        let item = Item::from_priority(priority).unwrap();
+        assert_eq!(item.priority(), priority);
+    }
+    assert_eq!(Item::from_priority(0), None);
+    assert_eq!(Item::from_priority(53), None);
+}
+
+#[test]
+fn _items_should_only_accept_ascii_letters() {
+    for symbol in ['1', ' ', '-', 'é', 'Ω'] {
+        assert_eq!(Item::new(symbol), Err(ParseItemError(symbol)));
+    }
+}
+
+#[test]
+fn _priority_should_reject_non_items() {
+    assert_eq!(get_priority("pL"), Ok(54));
+    assert_eq!(get_priority(""), Ok(0));
+    assert_eq!(get_priority("p-L"), Err(ParseItemError('-')));
+}
+
+#[test]
+fn _rucksack_should_split_into_compartments() {
+    let rucksack: Rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse().unwrap();
+    let (first, second) = rucksack.compartments();
+
+    assert_eq!(first.len(), 12);
+    assert_eq!(second.len(), 12);
+    assert_eq!(rucksack.items().len(), 24);
+    assert_eq!(rucksack.shared_items().to_string(), "p");
+    assert_eq!(rucksack.shared_items().priority_sum(), 16);
+}
+
+#[test]
+fn _rucksack_should_reject_malformed_lines() {
+    assert_eq!(
+        "abc".parse::<Rucksack>(),
+        Err(ParseRucksackError::UnevenCompartments(3))
+    );
+    assert_eq!(
+        "ab1c".parse::<Rucksack>(),
+        Err(ParseRucksackError::InvalidItem {
+            position: 3,
+            error: ParseItemError('1')
+        })
+    );
+}
+
+#[test]
+fn _parse_rucksacks_should_report_line() {
+    let rucksacks = parse_rucksacks("abca\n\nAbCA\n").unwrap();
+    assert_eq!(rucksacks.len(), 2);
+
+    assert_eq!(
+        parse_rucksacks("abca\nabc\n"),
+        Err(ParseRucksacksError {
+            line: 2,
+            error: ParseRucksackError::UnevenCompartments(3)
+        })
+    );
+    assert_eq!(
+        parse_rucksacks("abca\nabc\n").unwrap_err().to_string(),
+        "Line 2: 3 items cannot be split into two equal compartments."
+    );
+}
+
+const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+#[test]
+fn _group_badges_should_find_one_badge_per_group() {
+    let rucksacks = parse_rucksacks(EXAMPLE).unwrap();
+
+    assert_eq!(
+        group_badges(&rucksacks, 3),
+        Ok(vec![
+            Badge {
+                group: 0,
+                item: Item::new('r').unwrap()
+            },
+            Badge {
+                group: 1,
+                item: Item::new('Z').unwrap()
+            },
+        ])
+    );
+}
+
+#[test]
+fn _group_badges_should_support_other_group_sizes() {
+    let rucksacks = parse_rucksacks("abcd\nbxyz\nCDab\nCxyz").unwrap();
+
+    let badges = group_badges(&rucksacks, 2).unwrap();
+    let symbols: Vec<(usize, char)> = badges
+        .iter()
+        .map(|badge| (badge.group, badge.item.symbol()))
+        .collect();
+    assert_eq!(symbols, [(0, 'b'), (1, 'C')]);
+    assert_eq!(badges.len(), 2);
+    assert!(group_badges(&rucksacks, 1).is_err());
+}
+
+#[test]
+fn _group_badges_should_reject_invalid_groups() {
+    let rucksacks = parse_rucksacks(EXAMPLE).unwrap();
+
+    assert_eq!(group_badges(&rucksacks, 0), Err(BadgeError::ZeroGroupSize));
+    assert_eq!(
+        group_badges(&rucksacks, 4),
+        Err(BadgeError::IncompleteGroup { group: 1, size: 2 })
+    );
+    assert_eq!(
+        group_badges(&rucksacks[..2], 2),
+        Err(BadgeError::MultipleBadges {
+            group: 0,
+            items: "frsFM".chars().collect()
+        })
+    );
+
+    let rucksacks = parse_rucksacks("abcd\nefgh").unwrap();
+    assert_eq!(
+        group_badges(&rucksacks, 2),
+        Err(BadgeError::NoBadge { group: 0 })
+    );
+}
+
+#[test]
+fn _group_badges_should_accept_no_rucksacks() {
+    assert_eq!(group_badges(&[], 3), Ok(Vec::new()));
+}
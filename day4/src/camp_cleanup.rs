@@ -1,14 +1,50 @@
-use std::str::FromStr;
+use std::{error::Error, fmt::Display, str::FromStr};
 
-#[derive(Debug)]
+use crate::interval::Interval;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReversedAssignmentError {
+    pub start: i32,
+    pub end: i32,
+}
+
+impl Display for ReversedAssignmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Assignment {}-{} ends before it starts.",
+            self.start, self.end
+        )
+    }
+}
+
+impl Error for ReversedAssignmentError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Assignment {
     start: i32,
     end: i32,
 }
 
 impl Assignment {
-    pub fn new(start: i32, end: i32) -> Self {
-        Assignment { start, end }
+    pub fn new(start: i32, end: i32) -> Result<Self, ReversedAssignmentError> {
+        if start > end {
+            return Err(ReversedAssignmentError { start, end });
+        }
+
+        Ok(Assignment { start, end })
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    pub fn interval(&self) -> Interval {
+        Interval::new(self.start, self.end).expect("assignments never end before they start")
     }
 
     pub fn contains(&self, other: &Assignment) -> bool {
@@ -16,12 +52,39 @@ impl Assignment {
     }
 
     pub fn overlaps_with(&self, other: &Assignment) -> bool {
-        (self.start <= other.start && self.end >= other.start)
-            || (self.start <= other.end && self.end >= other.end)
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseAssignementErr {
+    Malformed,
+    Reversed(ReversedAssignmentError),
+}
+
+impl Display for ParseAssignementErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAssignementErr::Malformed => write!(f, "Expected a `start-end` assignment."),
+            ParseAssignementErr::Reversed(error) => write!(f, "{error}"),
+        }
     }
 }
 
-pub struct ParseAssignementErr;
+impl Error for ParseAssignementErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseAssignementErr::Malformed => None,
+            ParseAssignementErr::Reversed(error) => Some(error),
+        }
+    }
+}
+
+impl From<ReversedAssignmentError> for ParseAssignementErr {
+    fn from(error: ReversedAssignmentError) -> Self {
+        ParseAssignementErr::Reversed(error)
+    }
+}
 
 impl FromStr for Assignment {
     type Err = ParseAssignementErr;
@@ -30,15 +93,15 @@ impl FromStr for Assignment {
         let parts: Vec<&str> = s.split("-").collect();
 
         if parts.len() != 2 {
-            return Err(Self::Err {});
+            return Err(ParseAssignementErr::Malformed);
         }
 
-        if let (Some(start), Some(end)) = (parts.get(0), parts.get(1)) {
-            let start: i32 = start.parse().map_err(|_| Self::Err {})?;
-            let end: i32 = end.parse().map_err(|_| Self::Err {})?;
-            Ok(Self { start, end })
+        if let (Some(start), Some(end)) = (parts.first(), parts.get(1)) {
+            let start: i32 = start.parse().map_err(|_| ParseAssignementErr::Malformed)?;
+            let end: i32 = end.parse().map_err(|_| ParseAssignementErr::Malformed)?;
+            Ok(Assignment::new(start, end)?)
         } else {
-            Err(Self::Err {})
+            Err(ParseAssignementErr::Malformed)
         }
     }
 }
@@ -48,5 +111,5 @@ pub fn has_containment(a1: &Assignment, a2: &Assignment) -> bool {
 }
 
 pub fn has_overlap(a1: &Assignment, a2: &Assignment) -> bool {
-    a1.overlaps_with(a2) || a2.overlaps_with(a1)
+    a1.overlaps_with(a2)
 }
@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display};
+
+use crate::{
+    camp_cleanup::{Assignment, ParseAssignementErr},
+    interval::{Interval, IntervalSet},
+    interval_tree::IntervalTree,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseCampError {
+    MissingComma { line: usize },
+    InvalidAssignment { line: usize, text: String },
+    ReversedAssignment { line: usize, text: String },
+}
+
+impl Display for ParseCampError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseCampError::MissingComma { line } => {
+                write!(f, "Line {line}: expected two comma separated assignments.")
+            }
+            ParseCampError::InvalidAssignment { line, text } => {
+                write!(f, "Line {line}: {text:?} is not a `start-end` assignment.")
+            }
+            ParseCampError::ReversedAssignment { line, text } => {
+                write!(f, "Line {line}: {text:?} ends before it starts.")
+            }
+        }
+    }
+}
+
+impl Error for ParseCampError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ElfId {
+    pub line: usize,
+    pub elf: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CampAssignment {
+    pub elf: ElfId,
+    pub interval: Interval,
+}
+
+pub fn parse_camp(input: &str) -> Result<Vec<CampAssignment>, ParseCampError> {
+    let mut assignments = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (first, second) = line
+            .trim()
+            .split_once(',')
+            .ok_or(ParseCampError::MissingComma { line: line_number })?;
+
+        for (elf, text) in [first, second].into_iter().enumerate() {
+            let interval = text
+                .parse::<Assignment>()
+                .map_err(|error| match error {
+                    ParseAssignementErr::Malformed => ParseCampError::InvalidAssignment {
+                        line: line_number,
+                        text: text.to_string(),
+                    },
+                    ParseAssignementErr::Reversed(_) => ParseCampError::ReversedAssignment {
+                        line: line_number,
+                        text: text.to_string(),
+                    },
+                })?
+                .interval();
+
+            assignments.push(CampAssignment {
+                elf: ElfId {
+                    line: line_number,
+                    elf: elf + 1,
+                },
+                interval,
+            });
+        }
+    }
+
+    Ok(assignments)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    segments: Vec<(Interval, usize)>,
+}
+
+impl Coverage {
+    pub fn new(assignments: &[CampAssignment]) -> Self {
+        assignments
+            .iter()
+            .map(|assignment| assignment.interval)
+            .collect()
+    }
+
+    pub fn segments(&self) -> &[(Interval, usize)] {
+        &self.segments
+    }
+
+    pub fn max_overlap(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|&(_, depth)| depth)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn covered_exactly(&self, elves: usize) -> IntervalSet {
+        self.segments
+            .iter()
+            .filter(|&&(_, depth)| depth == elves)
+            .map(|&(interval, _)| interval)
+            .collect()
+    }
+
+    pub fn sections_covered_exactly(&self, elves: usize) -> u64 {
+        self.segments
+            .iter()
+            .filter(|&&(_, depth)| depth == elves)
+            .map(|(interval, _)| interval.length())
+            .sum()
+    }
+
+    pub fn depth_histogram(&self) -> Vec<u64> {
+        let mut histogram = vec![0; self.max_overlap() + 1];
+
+        for (interval, depth) in &self.segments {
+            histogram[*depth] += interval.length();
+        }
+
+        histogram
+    }
+}
+
+impl FromIterator<Interval> for Coverage {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut events: Vec<(i64, isize)> = Vec::new();
+        for interval in iter {
+            events.push((interval.start() as i64, 1));
+            events.push((interval.end() as i64 + 1, -1));
+        }
+        events.sort_unstable();
+
+        let mut segments = Vec::new();
+        let mut open: Option<(i64, usize)> = None;
+        let mut depth: isize = 0;
+        let mut index = 0;
+
+        while index < events.len() {
+            let position = events[index].0;
+            while index < events.len() && events[index].0 == position {
+                depth += events[index].1;
+                index += 1;
+            }
+
+            if open.is_some_and(|(_, open_depth)| open_depth == depth as usize) {
+                continue;
+            }
+            if let Some((start, open_depth)) = open {
+                if let Some(segment) = Interval::new(start as i32, (position - 1) as i32) {
+                    segments.push((segment, open_depth));
+                }
+            }
+            open = Some((position, depth as usize));
+        }
+
+        Coverage { segments }
+    }
+}
+
+pub fn overlapping_pairs(assignments: &[CampAssignment]) -> Vec<(ElfId, ElfId)> {
+    let tree = IntervalTree::new(
+        assignments
+            .iter()
+            .map(|assignment| (assignment.interval, assignment.elf))
+            .collect(),
+    );
+
+    let mut pairs: Vec<(ElfId, ElfId)> = assignments
+        .iter()
+        .flat_map(|assignment| {
+            tree.overlapping(&assignment.interval)
+                .into_iter()
+                .filter(|(_, other)| other.line > assignment.elf.line)
+                .map(|&(_, other)| (assignment.elf, other))
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    pairs
+}
+
+pub fn count_overlapping_pairs(assignments: &[CampAssignment]) -> u64 {
+    let mut ends: Vec<i32> = assignments
+        .iter()
+        .map(|assignment| assignment.interval.end())
+        .collect();
+    ends.sort_unstable();
+
+    // Two intervals are disjoint exactly when one ends before the other starts.
+    let disjoint: u64 = assignments
+        .iter()
+        .map(|assignment| ends.partition_point(|&end| end < assignment.interval.start()) as u64)
+        .sum();
+
+    let count = assignments.len() as u64;
+    let all_pairs = count * count.saturating_sub(1) / 2;
+
+    let mut by_line: BTreeMap<usize, Vec<Interval>> = BTreeMap::new();
+    for assignment in assignments {
+        by_line
+            .entry(assignment.elf.line)
+            .or_default()
+            .push(assignment.interval);
+    }
+
+    let same_line: u64 = by_line
+        .values()
+        .map(|intervals| {
+            intervals
+                .iter()
+                .enumerate()
+                .flat_map(|(index, first)| {
+                    intervals[index + 1..]
+                        .iter()
+                        .filter(move |second| first.overlaps(second))
+                })
+                .count() as u64
+        })
+        .sum();
+
+    all_pairs - disjoint - same_line
+}
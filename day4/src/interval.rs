@@ -0,0 +1,210 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: i32,
+    end: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalUnion {
+    Merged(Interval),
+    Disjoint(Interval, Interval),
+}
+
+impl Interval {
+    pub fn new(start: i32, end: i32) -> Option<Self> {
+        if start <= end {
+            Some(Interval { start, end })
+        } else {
+            None
+        }
+    }
+
+    pub fn section(section: i32) -> Self {
+        Interval {
+            start: section,
+            end: section,
+        }
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    pub fn length(&self) -> u64 {
+        (self.end as i64 - self.start as i64 + 1) as u64
+    }
+
+    pub fn contains_section(&self, section: i32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    fn touches(&self, other: &Interval) -> bool {
+        self.start as i64 <= other.end as i64 + 1 && other.start as i64 <= self.end as i64 + 1
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn union(&self, other: &Interval) -> IntervalUnion {
+        if self.touches(other) {
+            IntervalUnion::Merged(Interval {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            })
+        } else if self.start < other.start {
+            IntervalUnion::Disjoint(*self, *other)
+        } else {
+            IntervalUnion::Disjoint(*other, *self)
+        }
+    }
+
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let mut remainder = Vec::with_capacity(2);
+        if self.start < other.start {
+            remainder.push(Interval {
+                start: self.start,
+                end: other.start - 1,
+            });
+        }
+        if other.end < self.end {
+            remainder.push(Interval {
+                start: other.end + 1,
+                end: self.end,
+            });
+        }
+
+        remainder
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let first = self
+            .intervals
+            .partition_point(|existing| (existing.end as i64) + 1 < interval.start as i64);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start as i64 <= interval.end as i64 + 1);
+
+        let mut merged = interval;
+        for existing in &self.intervals[first..last] {
+            merged.start = merged.start.min(existing.start);
+            merged.end = merged.end.max(existing.end);
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+
+    pub fn span(&self) -> Option<Interval> {
+        let first = self.intervals.first()?;
+        let last = self.intervals.last()?;
+
+        Some(Interval {
+            start: first.start,
+            end: last.end,
+        })
+    }
+
+    pub fn contains_section(&self, section: i32) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end < section);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains_section(section))
+    }
+
+    pub fn uncovered(&self, within: &Interval) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut next = within.start as i64;
+
+        for covered in &self.intervals {
+            if covered.end < within.start {
+                continue;
+            }
+            if covered.start > within.end {
+                break;
+            }
+            if covered.start as i64 > next {
+                intervals.push(Interval {
+                    start: next as i32,
+                    end: covered.start - 1,
+                });
+            }
+            next = covered.end as i64 + 1;
+        }
+
+        if next <= within.end as i64 {
+            intervals.push(Interval {
+                start: next as i32,
+                end: within.end,
+            });
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn gaps(&self) -> IntervalSet {
+        self.span()
+            .map_or_else(IntervalSet::new, |span| self.uncovered(&span))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = IntervalSet::new();
+
+        for interval in iter {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
@@ -0,0 +1,88 @@
+use crate::interval::Interval;
+
+// Entries are kept sorted by start and viewed as an implicit balanced tree: the middle of any
+// range is its root, and `max_ends` holds the furthest end found under that root.
+#[derive(Clone, Debug, Default)]
+pub struct IntervalTree<T> {
+    entries: Vec<(Interval, T)>,
+    max_ends: Vec<i32>,
+}
+
+impl<T> IntervalTree<T> {
+    pub fn new(mut entries: Vec<(Interval, T)>) -> Self {
+        entries.sort_by_key(|(interval, _)| (interval.start(), interval.end()));
+
+        let mut tree = IntervalTree {
+            max_ends: entries.iter().map(|(interval, _)| interval.end()).collect(),
+            entries,
+        };
+        tree.build(0, tree.entries.len());
+
+        tree
+    }
+
+    fn build(&mut self, low: usize, high: usize) -> Option<i32> {
+        if low >= high {
+            return None;
+        }
+
+        let middle = low + (high - low) / 2;
+        let left = self.build(low, middle);
+        let right = self.build(middle + 1, high);
+
+        let max_end = [left, right]
+            .into_iter()
+            .flatten()
+            .fold(self.max_ends[middle], i32::max);
+        self.max_ends[middle] = max_end;
+
+        Some(max_end)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> &[(Interval, T)] {
+        &self.entries
+    }
+
+    pub fn overlapping(&self, query: &Interval) -> Vec<&(Interval, T)> {
+        let mut found = Vec::new();
+        self.collect_overlapping(query, 0, self.entries.len(), &mut found);
+        found
+    }
+
+    fn collect_overlapping<'a>(
+        &'a self,
+        query: &Interval,
+        low: usize,
+        high: usize,
+        found: &mut Vec<&'a (Interval, T)>,
+    ) {
+        if low >= high {
+            return;
+        }
+
+        let middle = low + (high - low) / 2;
+        if self.max_ends[middle] < query.start() {
+            return;
+        }
+
+        self.collect_overlapping(query, low, middle, found);
+
+        let entry = &self.entries[middle];
+        if entry.0.start() > query.end() {
+            return;
+        }
+        if entry.0.overlaps(query) {
+            found.push(entry);
+        }
+
+        self.collect_overlapping(query, middle + 1, high, found);
+    }
+}
@@ -1 +1,5 @@
 pub mod camp_cleanup;
+pub mod coverage;
+pub mod interval;
+pub mod interval_tree;
+pub mod schedule;
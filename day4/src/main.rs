@@ -1,39 +1,28 @@
-use std::fs;
+use std::{error::Error, fs};
 
-use day4::camp_cleanup::*;
+use day4::coverage::*;
 
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn Error>> {
     let input = fs::read_to_string("./data/day4.txt")?;
-    let lines = input.lines();
-    // println!("{lines:?}");
+    let assignments = parse_camp(&input)?;
 
-    let assignment_str_pairs: Vec<Vec<&str>> = lines
-        .map(|line| line.split(",").collect::<Vec<&str>>())
+    let pairs: Vec<(&CampAssignment, &CampAssignment)> = assignments
+        .chunks_exact(2)
+        .map(|pair| (&pair[0], &pair[1]))
         .collect();
-    // println!("{assignment_str_pairs:?}");
 
-    let assignment_pairs: Vec<Vec<Assignment>> = assignment_str_pairs
+    let containment_count = pairs
         .iter()
-        .map(|pair| pair.iter().filter_map(|&s| s.parse().ok()).collect())
-        .collect();
-    // println!("{assignment_pairs:?}");
-
-    let containment_assignments: Vec<&Vec<Assignment>> = assignment_pairs
-        .iter()
-        .filter(|pair| has_containment(pair.get(0).unwrap(), pair.get(1).unwrap()))
-        .collect();
-    // println!("{containment_assignments:?}");
-
-    let containment_count = containment_assignments.len();
+        .filter(|(first, second)| {
+            first.interval.contains(&second.interval) || second.interval.contains(&first.interval)
+        })
+        .count();
     println!("Part 1: {containment_count}");
 
-    let overlapping_assignments: Vec<&Vec<Assignment>> = assignment_pairs
+    let overlapping_count = pairs
         .iter()
-        .filter(|pair| has_overlap(pair.get(0).unwrap(), pair.get(1).unwrap()))
-        .collect();
-    // println!("{overlapping_assignments:?}");
-
-    let overlapping_count = overlapping_assignments.len();
+        .filter(|(first, second)| first.interval.overlaps(&second.interval))
+        .count();
     println!("Part 2: {overlapping_count}");
 
     Ok(())
@@ -0,0 +1,69 @@
+use crate::{
+    coverage::{CampAssignment, ElfId},
+    interval::IntervalSet,
+};
+
+pub fn minimum_cover(assignments: &[CampAssignment]) -> Vec<ElfId> {
+    let target: IntervalSet = assignments
+        .iter()
+        .map(|assignment| assignment.interval)
+        .collect();
+
+    let mut by_start: Vec<&CampAssignment> = assignments.iter().collect();
+    by_start.sort_by_key(|assignment| assignment.interval.start());
+
+    let mut chosen = Vec::new();
+    let mut candidates = by_start.into_iter().peekable();
+
+    for component in target.intervals() {
+        let mut next = component.start() as i64;
+
+        while next <= component.end() as i64 {
+            // Of the assignments covering `next`, keep the one reaching furthest.
+            let mut best: Option<&CampAssignment> = None;
+            while let Some(candidate) =
+                candidates.next_if(|candidate| candidate.interval.start() as i64 <= next)
+            {
+                if best.is_none_or(|best| candidate.interval.end() > best.interval.end()) {
+                    best = Some(candidate);
+                }
+            }
+
+            let Some(best) = best else {
+                break;
+            };
+            chosen.push(best.elf);
+            next = best.interval.end() as i64 + 1;
+        }
+    }
+
+    chosen.sort_unstable();
+    chosen
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schedule {
+    pub kept: Vec<ElfId>,
+    pub removed: Vec<ElfId>,
+}
+
+pub fn non_overlapping_schedule(assignments: &[CampAssignment]) -> Schedule {
+    let mut by_end: Vec<&CampAssignment> = assignments.iter().collect();
+    by_end.sort_by_key(|assignment| (assignment.interval.end(), assignment.elf));
+
+    let mut schedule = Schedule::default();
+    let mut last_end: Option<i32> = None;
+
+    for assignment in by_end {
+        if last_end.is_none_or(|end| end < assignment.interval.start()) {
+            last_end = Some(assignment.interval.end());
+            schedule.kept.push(assignment.elf);
+        } else {
+            schedule.removed.push(assignment.elf);
+        }
+    }
+
+    schedule.kept.sort_unstable();
+    schedule.removed.sort_unstable();
+    schedule
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeSet;
+
+use day4::camp_cleanup::*;
+use proptest::prelude::*;
+
+fn assignment(start: i32, end: i32) -> Assignment {
+    Assignment::new(start, end).unwrap()
+}
+
+fn sections(assignment: &Assignment) -> BTreeSet<i32> {
+    (assignment.start()..=assignment.end()).collect()
+}
+
+fn assignments() -> impl Strategy<Value = Assignment> {
+    (-20..20, 0..15).prop_map(|(start, length)| assignment(start, start + length))
+}
+
+#[test]
+fn _new_should_reject_reversed_assignments() {
+    assert_eq!(
+        Assignment::new(6, 2),
+        Err(ReversedAssignmentError { start: 6, end: 2 })
+    );
+    assert_eq!(assignment(4, 4).interval().length(), 1);
+}
+
+#[test]
+fn _parse_should_report_errors() {
+    assert_eq!("2-4".parse(), Ok(assignment(2, 4)));
+    assert_eq!(
+        "2-4-6".parse::<Assignment>(),
+        Err(ParseAssignementErr::Malformed)
+    );
+    assert_eq!(
+        "2-x".parse::<Assignment>(),
+        Err(ParseAssignementErr::Malformed)
+    );
+    assert_eq!(
+        "8-4".parse::<Assignment>(),
+        Err(ParseAssignementErr::Reversed(ReversedAssignmentError {
+            start: 8,
+            end: 4
+        }))
+    );
+}
+
+#[test]
+fn _overlaps_with_should_detect_enclosing_assignments() {
+    let outer = assignment(2, 8);
+    let inner = assignment(3, 7);
+
+    assert!(outer.overlaps_with(&inner));
+    assert!(inner.overlaps_with(&outer));
+    assert!(!assignment(2, 4).overlaps_with(&assignment(6, 8)));
+    assert!(assignment(2, 6).overlaps_with(&assignment(6, 8)));
+}
+
+proptest! {
+    #[test]
+    fn _new_should_accept_only_ordered_bounds(start in -100..100, end in -100..100) {
+        prop_assert_eq!(Assignment::new(start, end).is_ok(), start <= end);
+    }
+
+    #[test]
+    fn _overlaps_with_should_match_section_oracle(a in assignments(), b in assignments()) {
+        let shared = sections(&a).intersection(&sections(&b)).count() > 0;
+
+        prop_assert_eq!(a.overlaps_with(&b), shared);
+        prop_assert_eq!(b.overlaps_with(&a), shared);
+        prop_assert_eq!(has_overlap(&a, &b), shared);
+    }
+
+    #[test]
+    fn _contains_should_match_section_oracle(a in assignments(), b in assignments()) {
+        prop_assert_eq!(a.contains(&b), sections(&b).is_subset(&sections(&a)));
+        prop_assert_eq!(
+            has_containment(&a, &b),
+            sections(&b).is_subset(&sections(&a)) || sections(&a).is_subset(&sections(&b))
+        );
+    }
+
+    #[test]
+    fn _interval_operations_should_match_section_oracle(a in assignments(), b in assignments()) {
+        let (a_sections, b_sections) = (sections(&a), sections(&b));
+        let (a, b) = (a.interval(), b.interval());
+
+        let intersection: BTreeSet<i32> = a
+            .intersection(&b)
+            .map(|interval| (interval.start()..=interval.end()).collect())
+            .unwrap_or_default();
+        prop_assert_eq!(intersection, &a_sections & &b_sections);
+
+        let difference: BTreeSet<i32> = a
+            .difference(&b)
+            .iter()
+            .flat_map(|interval| interval.start()..=interval.end())
+            .collect();
+        prop_assert_eq!(difference, &a_sections - &b_sections);
+        prop_assert_eq!(a.length(), a_sections.len() as u64);
+    }
+}
@@ -0,0 +1,179 @@
+use day4::{coverage::*, interval::Interval, interval_tree::IntervalTree};
+
+const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+fn interval(start: i32, end: i32) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+fn elf(line: usize, elf: usize) -> ElfId {
+    ElfId { line, elf }
+}
+
+fn brute_force_depths(assignments: &[CampAssignment]) -> Vec<(i32, usize)> {
+    (0..=12)
+        .map(|section| {
+            let depth = assignments
+                .iter()
+                .filter(|assignment| assignment.interval.contains_section(section))
+                .count();
+            (section, depth)
+        })
+        .collect()
+}
+
+#[test]
+fn _parse_camp_should_number_elves_by_line() {
+    let assignments = parse_camp("2-4,6-8\n\n5-7,7-9\n").unwrap();
+
+    assert_eq!(
+        assignments,
+        [
+            CampAssignment {
+                elf: elf(1, 1),
+                interval: interval(2, 4)
+            },
+            CampAssignment {
+                elf: elf(1, 2),
+                interval: interval(6, 8)
+            },
+            CampAssignment {
+                elf: elf(3, 1),
+                interval: interval(5, 7)
+            },
+            CampAssignment {
+                elf: elf(3, 2),
+                interval: interval(7, 9)
+            },
+        ]
+    );
+}
+
+#[test]
+fn _parse_camp_should_report_malformed_lines() {
+    assert_eq!(
+        parse_camp("2-4,6-8\n2-4"),
+        Err(ParseCampError::MissingComma { line: 2 })
+    );
+    assert_eq!(
+        parse_camp("2-4,6-x"),
+        Err(ParseCampError::InvalidAssignment {
+            line: 1,
+            text: "6-x".to_string()
+        })
+    );
+    assert_eq!(
+        parse_camp("\n4-2,6-8"),
+        Err(ParseCampError::ReversedAssignment {
+            line: 2,
+            text: "4-2".to_string()
+        })
+    );
+}
+
+#[test]
+fn _coverage_should_match_brute_force() {
+    let assignments = parse_camp(EXAMPLE).unwrap();
+    let coverage = Coverage::new(&assignments);
+
+    for (section, depth) in brute_force_depths(&assignments) {
+        let covering = coverage
+            .segments()
+            .iter()
+            .find(|(segment, _)| segment.contains_section(section))
+            .map_or(0, |&(_, depth)| depth);
+        assert_eq!(covering, depth, "section {section}");
+    }
+}
+
+#[test]
+fn _coverage_should_count_sections_by_depth() {
+    let assignments = parse_camp(EXAMPLE).unwrap();
+    let coverage = Coverage::new(&assignments);
+
+    let mut expected = vec![0; coverage.max_overlap() + 1];
+    for (section, depth) in brute_force_depths(&assignments) {
+        if (2..=9).contains(&section) {
+            expected[depth] += 1;
+        }
+    }
+
+    assert_eq!(coverage.depth_histogram(), expected);
+    assert_eq!(coverage.max_overlap(), 8);
+    assert_eq!(coverage.covered_exactly(8).intervals(), [interval(6, 6)]);
+    assert_eq!(coverage.sections_covered_exactly(1), 1);
+    assert_eq!(coverage.covered_exactly(1).intervals(), [interval(9, 9)]);
+}
+
+#[test]
+fn _coverage_should_report_gaps_as_depth_zero() {
+    let coverage: Coverage = [interval(1, 2), interval(5, 6), interval(3, 3)]
+        .into_iter()
+        .collect();
+
+    assert_eq!(
+        coverage.segments(),
+        [
+            (interval(1, 3), 1),
+            (interval(4, 4), 0),
+            (interval(5, 6), 1)
+        ]
+    );
+    assert_eq!(coverage.depth_histogram(), [1, 5]);
+    assert_eq!(Coverage::default().max_overlap(), 0);
+}
+
+#[test]
+fn _interval_tree_should_find_overlaps() {
+    let tree = IntervalTree::new(
+        [(1, 3), (2, 9), (4, 5), (6, 6), (8, 12), (10, 11)]
+            .into_iter()
+            .enumerate()
+            .map(|(index, (start, end))| (interval(start, end), index))
+            .collect(),
+    );
+
+    let mut found: Vec<usize> = tree
+        .overlapping(&interval(5, 8))
+        .into_iter()
+        .map(|&(_, index)| index)
+        .collect();
+    found.sort_unstable();
+
+    assert_eq!(found, [1, 2, 3, 4]);
+    assert!(tree.overlapping(&interval(13, 20)).is_empty());
+    assert_eq!(tree.len(), 6);
+}
+
+#[test]
+fn _overlapping_pairs_should_only_pair_different_lines() {
+    let assignments = parse_camp("1-2,2-3\n3-4,8-9\n9-9,5-5").unwrap();
+
+    assert_eq!(
+        overlapping_pairs(&assignments),
+        [(elf(1, 2), elf(2, 1)), (elf(2, 2), elf(3, 1)),]
+    );
+    assert_eq!(count_overlapping_pairs(&assignments), 2);
+}
+
+#[test]
+fn _overlapping_pair_count_should_match_pairs() {
+    let assignments = parse_camp(EXAMPLE).unwrap();
+
+    let mut expected = Vec::new();
+    for (index, first) in assignments.iter().enumerate() {
+        for second in &assignments[index + 1..] {
+            if first.elf.line != second.elf.line && first.interval.overlaps(&second.interval) {
+                expected.push((first.elf, second.elf));
+            }
+        }
+    }
+
+    assert_eq!(overlapping_pairs(&assignments), expected);
+    assert_eq!(count_overlapping_pairs(&assignments), expected.len() as u64);
+}
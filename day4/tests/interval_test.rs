@@ -0,0 +1,97 @@
+use day4::{camp_cleanup::Assignment, interval::*};
+
+fn interval(start: i32, end: i32) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+#[test]
+fn _interval_should_reject_reversed_bounds() {
+    assert_eq!(Interval::new(5, 4), None);
+    assert_eq!(Interval::new(4, 4), Some(Interval::section(4)));
+    assert_eq!(Assignment::new(2, 6).unwrap().interval(), interval(2, 6));
+}
+
+#[test]
+fn _interval_should_count_sections() {
+    assert_eq!(interval(2, 4).length(), 3);
+    assert_eq!(Interval::section(7).length(), 1);
+    assert_eq!(interval(i32::MIN, i32::MAX).length(), 1 << 32);
+    assert_eq!(interval(2, 4).to_string(), "2-4");
+}
+
+#[test]
+fn _interval_should_intersect() {
+    assert_eq!(
+        interval(2, 6).intersection(&interval(4, 8)),
+        Some(interval(4, 6))
+    );
+    assert_eq!(
+        interval(2, 8).intersection(&interval(3, 7)),
+        Some(interval(3, 7))
+    );
+    assert_eq!(
+        interval(2, 4).intersection(&interval(4, 8)),
+        Some(Interval::section(4))
+    );
+    assert_eq!(interval(2, 3).intersection(&interval(4, 8)), None);
+}
+
+#[test]
+fn _interval_union_should_merge_touching_intervals() {
+    assert_eq!(
+        interval(2, 6).union(&interval(4, 8)),
+        IntervalUnion::Merged(interval(2, 8))
+    );
+    assert_eq!(
+        interval(5, 8).union(&interval(2, 4)),
+        IntervalUnion::Merged(interval(2, 8))
+    );
+    assert_eq!(
+        interval(6, 8).union(&interval(2, 4)),
+        IntervalUnion::Disjoint(interval(2, 4), interval(6, 8))
+    );
+}
+
+#[test]
+fn _interval_difference_should_leave_up_to_two_pieces() {
+    assert_eq!(
+        interval(2, 8).difference(&interval(4, 5)),
+        [interval(2, 3), interval(6, 8)]
+    );
+    assert_eq!(interval(2, 8).difference(&interval(1, 5)), [interval(6, 8)]);
+    assert_eq!(interval(2, 8).difference(&interval(6, 9)), [interval(2, 5)]);
+    assert_eq!(interval(2, 8).difference(&interval(1, 9)), []);
+    assert_eq!(interval(2, 8).difference(&interval(9, 9)), [interval(2, 8)]);
+}
+
+#[test]
+fn _interval_set_should_merge_assignments() {
+    let set: IntervalSet = [(2, 4), (6, 8), (2, 3), (4, 5), (12, 14), (20, 22)]
+        .into_iter()
+        .map(|(start, end)| interval(start, end))
+        .collect();
+
+    assert_eq!(
+        set.intervals(),
+        [interval(2, 8), interval(12, 14), interval(20, 22)]
+    );
+    assert_eq!(set.len(), 13);
+    assert_eq!(set.span(), Some(interval(2, 22)));
+    assert!(set.contains_section(13));
+    assert!(!set.contains_section(9));
+    assert!(!set.contains_section(23));
+}
+
+#[test]
+fn _interval_set_should_find_uncovered_sections() {
+    let set: IntervalSet = [interval(3, 5), interval(8, 9)].into_iter().collect();
+
+    assert_eq!(set.gaps().intervals(), [interval(6, 7)]);
+    assert_eq!(
+        set.uncovered(&interval(1, 10)).intervals(),
+        [interval(1, 2), interval(6, 7), interval(10, 10)]
+    );
+    assert_eq!(set.uncovered(&interval(4, 5)).intervals(), []);
+    assert_eq!(set.uncovered(&interval(1, 10)).len(), 5);
+    assert!(IntervalSet::new().gaps().is_empty());
+}
@@ -0,0 +1,102 @@
+use day4::{coverage::*, interval::IntervalSet, schedule::*};
+
+const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+fn elf(line: usize, elf: usize) -> ElfId {
+    ElfId { line, elf }
+}
+
+fn subsets(assignments: &[CampAssignment]) -> impl Iterator<Item = Vec<CampAssignment>> + '_ {
+    (0..1u32 << assignments.len()).map(|mask| {
+        assignments
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| mask & (1 << index) != 0)
+            .map(|(_, assignment)| *assignment)
+            .collect()
+    })
+}
+
+fn covered(assignments: &[CampAssignment]) -> IntervalSet {
+    assignments
+        .iter()
+        .map(|assignment| assignment.interval)
+        .collect()
+}
+
+fn is_disjoint(assignments: &[CampAssignment]) -> bool {
+    assignments.iter().enumerate().all(|(index, first)| {
+        assignments[index + 1..]
+            .iter()
+            .all(|second| !first.interval.overlaps(&second.interval))
+    })
+}
+
+fn select(assignments: &[CampAssignment], elves: &[ElfId]) -> Vec<CampAssignment> {
+    assignments
+        .iter()
+        .filter(|assignment| elves.contains(&assignment.elf))
+        .copied()
+        .collect()
+}
+
+#[test]
+fn _minimum_cover_should_reference_lines() {
+    let assignments = parse_camp("1-3,2-5\n4-9,8-8\n12-14,13-13").unwrap();
+
+    assert_eq!(
+        minimum_cover(&assignments),
+        [elf(1, 1), elf(2, 1), elf(3, 1)]
+    );
+    assert!(minimum_cover(&[]).is_empty());
+}
+
+#[test]
+fn _minimum_cover_should_match_brute_force() {
+    let assignments = parse_camp(EXAMPLE).unwrap();
+    let target = covered(&assignments);
+
+    let smallest = subsets(&assignments)
+        .filter(|subset| covered(subset) == target)
+        .map(|subset| subset.len())
+        .min()
+        .unwrap();
+
+    let cover = minimum_cover(&assignments);
+    assert_eq!(cover.len(), smallest);
+    assert_eq!(covered(&select(&assignments, &cover)), target);
+}
+
+#[test]
+fn _schedule_should_remove_overlapping_elves() {
+    let assignments = parse_camp("1-3,2-5\n4-9,8-8\n12-14,13-13").unwrap();
+
+    assert_eq!(
+        non_overlapping_schedule(&assignments),
+        Schedule {
+            kept: vec![elf(1, 1), elf(2, 2), elf(3, 2)],
+            removed: vec![elf(1, 2), elf(2, 1), elf(3, 1)],
+        }
+    );
+}
+
+#[test]
+fn _schedule_should_match_brute_force() {
+    let assignments = parse_camp(EXAMPLE).unwrap();
+
+    let largest = subsets(&assignments)
+        .filter(|subset| is_disjoint(subset))
+        .map(|subset| subset.len())
+        .max()
+        .unwrap();
+
+    let schedule = non_overlapping_schedule(&assignments);
+    assert_eq!(schedule.kept.len(), largest);
+    assert_eq!(schedule.removed.len(), assignments.len() - largest);
+    assert!(is_disjoint(&select(&assignments, &schedule.kept)));
+}